@@ -0,0 +1,73 @@
+// Benchmarks pushing then popping N elements through the heap allocated FixedSizedStack (check out unsafe_ops.rs)
+// against the safe Vec backed Stack (check out collections.rs) and a plain Vec, giving concrete numbers to the allocation performance comments of the examples.
+// Run via "cargo bench", criterion reports the time per iteration along with the throughput in elements/s.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use rust_playground::fixed_sized_stack::{FixedSizedStack, LibcAllocator, StdAllocator};
+use rust_playground::stack::Stack;
+
+const N: usize = 1024;
+
+fn stack_push_pop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop");
+    group.throughput(Throughput::Elements(N as u64));
+
+    // The containers are created once outside of the measured closure so only the push/pop cost is measured,
+    // not the allocation of the containers themselves.
+
+    let mut stack = FixedSizedStack::<usize, N, LibcAllocator>::new();
+    group.bench_function("FixedSizedStack (libc)", |b| {
+        b.iter(|| {
+            for i in 0..N {
+                stack.push(i);
+            }
+            while !stack.empty() {
+                black_box(stack.pop());
+            }
+        })
+    });
+
+    let mut stack = FixedSizedStack::<usize, N, StdAllocator>::new();
+    group.bench_function("FixedSizedStack (std)", |b| {
+        b.iter(|| {
+            for i in 0..N {
+                stack.push(i);
+            }
+            while !stack.empty() {
+                black_box(stack.pop());
+            }
+        })
+    });
+
+    // Stack<T> grows on demand, so it is filled and emptied once up front to make its Vec reach the capacity of N
+    // like the other containers, otherwise the first iteration would also measure the reallocations.
+    let mut stack: Stack<usize> = (0..N).collect();
+    while stack.pop().is_some() {}
+    group.bench_function("Stack", |b| {
+        b.iter(|| {
+            for i in 0..N {
+                stack.push(i);
+            }
+            while let Some(e) = stack.pop() {
+                black_box(e);
+            }
+        })
+    });
+
+    let mut vec = Vec::with_capacity(N);
+    group.bench_function("Vec", |b| {
+        b.iter(|| {
+            for i in 0..N {
+                vec.push(i);
+            }
+            while let Some(e) = vec.pop() {
+                black_box(e);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, stack_push_pop);
+criterion_main!(benches);
@@ -4,36 +4,93 @@ use crate::*; //Import the entire crate.
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
 
+// A threaded announcer that announces every one of its announcements in order, sleeping 'interval' after each one.
+// The announcements are also sent through the optional output channel, which lets the caller observe them.
+pub struct Announcer {
+    announcements: Vec<String>,
+    interval: Duration,
+    output: Option<std::sync::mpsc::Sender<String>>,
+}
+
+impl Announcer {
+    pub fn new(announcements: Vec<String>, interval: Duration) -> Self {
+        Announcer {
+            announcements,
+            interval,
+            output: None,
+        }
+    }
+
+    pub fn with_output(mut self, output: std::sync::mpsc::Sender<String>) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    // Consumes the announcer, moving it into the spawned thread, the returned JoinHandle can be used to wait for it.
+    pub fn spawn(self) -> JoinHandle<()> {
+        let Announcer {
+            mut announcements,
+            interval,
+            output,
+        } = self;
+
+        // Reverse so that popping from the end of the Vec yields the announcements in their original order.
+        announcements.reverse();
+
+        // We can prepend the thread closure with the 'move' keyword which allows us to move the announcements into the
+        // closure. rendering them unusable thereafter.
+        thread::spawn(move || {
+            // keep popping announcements until the Vec is empty.
+            while let Some(announcement) = announcements.pop() {
+                println!("{}", announcement);
+                if let Some(output) = &output {
+                    // the receiver may have been dropped, in which case there's simply no one listening anymore.
+                    let _ = output.send(announcement);
+                }
+                thread::sleep(interval);
+            }
+        })
+    }
+}
+
 #[test]
 pub fn threading() {
     example_prologue!("threading");
     // == Example ==
-    // We will create a threaded announcer that pops the next announcement from a Vec of announcements
-    // and prints it every 3 seconds, we concurrently wait on the thread till it finishes to return this function.
-
-    let mut announcements = vec![
-        "Hello Rust Fans!",
-        "Rust is an amazing lang!",
-        "Have a good day!",
+    // We will create a threaded announcer that announces the next announcement from a Vec of announcements
+    // and prints it every second, we concurrently wait on the thread till it finishes to return this function.
+    // Check out the module level Announcer declared above this example.
+
+    let announcements = vec![
+        "Hello Rust Fans!".to_owned(),
+        "Rust is an amazing lang!".to_owned(),
+        "Have a good day!".to_owned(),
     ];
 
-    // We can prepend the thread closure with the 'move' keyword which allows us to move the announcement into the
-    // closure. rendering it unusable thereafter.
+    let thread_handle = Announcer::new(announcements, Duration::from_secs(1)).spawn();
 
-    let thread_handle = thread::spawn(move || {
-        // returns a JoinHandle which can be used to join this thread.
-        while !announcements.is_empty() {
-            // keep popping announcements until the Vec is empty.
-            println!("{}", announcements.pop().unwrap());
-            thread::sleep(Duration::from_secs(1)); //sleep for 1 seconds.
-        }
-    });
-
-    //'announcements' is no longer accessible here since its been moved into the thread's closure.
+    //'announcements' is no longer accessible here since its been moved into the announcer and then into the thread's closure.
 
     thread_handle.join().unwrap(); // wait for the thread to finish before we return this test.
 }
 
+#[test]
+pub fn announcer_output() {
+    example_prologue!("announcer_output");
+
+    let announcements = ["first", "second", "third"].map(String::from).to_vec();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = Announcer::new(announcements.clone(), Duration::from_millis(5))
+        .with_output(tx)
+        .spawn();
+    handle.join().unwrap();
+
+    // The announcer (and its sender) is gone once the thread finished, so iterating the receiver ends
+    // after the buffered announcements.
+    assert_eq!(rx.iter().collect::<Vec<_>>(), announcements);
+}
+
 #[test]
 pub fn arc() {
     example_prologue!("sync_primitives : Arc<T>");
@@ -47,6 +104,7 @@ pub fn arc() {
          atomic operations for its reference counting which is suitable for multi-threaded contexts.
          it requires using std::sync::Arc.,
          ** Use RC<T> for single threaded applications for a lower overhead.
+         (run 'cargo bench --bench rc_arc_bench' to measure the difference).
         "
     )
 }
@@ -67,6 +125,28 @@ pub fn weak() {
         );
 }
 
+// Joins every thread, returning their results in the order of the handles if none of them panicked.
+// Otherwise the payloads of all the panicked threads are returned, so unlike calling join().unwrap() on each
+// handle, every thread is still waited for and the caller gets to know how many (and which) of them panicked.
+// A panic payload is whatever was passed to panic!, usually a &str or a String that can be recovered via downcast_ref.
+pub fn join_all<T>(handles: Vec<JoinHandle<T>>) -> Result<Vec<T>, Vec<Box<dyn std::any::Any + Send>>> {
+    let mut results = vec![];
+    let mut panics = vec![];
+
+    for handle in handles {
+        match handle.join() {
+            Ok(result) => results.push(result),
+            Err(payload) => panics.push(payload),
+        }
+    }
+
+    if panics.is_empty() {
+        Ok(results)
+    } else {
+        Err(panics)
+    }
+}
+
 #[test]
 pub fn barrier() {
     //Ensures multiple threads will wait for each other to reach a point in the program, before continuing execution all together.
@@ -101,9 +181,7 @@ pub fn barrier() {
         }));
     }
     // Wait for other threads to finish before returning the test by joining the stored handles.
-    for handle in thread_handles {
-        handle.join().unwrap();
-    }
+    join_all(thread_handles).expect("Some of the barrier threads panicked");
 }
 
 // The following test 'mutexes' requires either removing the --release flag from the test command line
@@ -354,6 +432,51 @@ pub fn once() {
     }
 }
 
+// Once only guarantees that a piece of code runs once, it doesn't store anything. OnceLock is its value holding
+// counterpart : the first 'get_or_init' call runs the initializer and stores its result, every other call (from any
+// thread) gets a reference to that same value, the threads arriving while it is being initialized block until it's done.
+// Placed in a static, it makes for a lazily initialized global value, computed on first access rather than at startup.
+#[derive(Debug)]
+pub struct Config {
+    pub name: String,
+    pub worker_threads: usize,
+}
+
+// Counts the initializer runs so that the test can check it only ran once.
+static CONFIG_INITS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+pub fn config() -> &'static Config {
+    static CONFIG: std::sync::OnceLock<Config> = std::sync::OnceLock::new();
+
+    CONFIG.get_or_init(|| {
+        CONFIG_INITS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(50)); // simulate an expensive computation (e.g. reading a file).
+        Config {
+            name: "rust-intuition".to_owned(),
+            worker_threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    })
+}
+
+#[test]
+pub fn once_lock_config() {
+    example_prologue!("once_lock_config");
+
+    // Every thread returns the address of the Config it observed.
+    let handles = (0..8)
+        .map(|_| thread::spawn(|| config() as *const Config as usize))
+        .collect::<Vec<_>>();
+    let addresses = join_all(handles).unwrap();
+
+    println!("Config : {:?}", config());
+
+    // All of them observed the very same instance, initialized exactly once.
+    assert!(addresses.iter().all(|&address| address == addresses[0]));
+    assert!(std::ptr::eq(config(), addresses[0] as *const Config));
+    assert_eq!(CONFIG_INITS.load(std::sync::atomic::Ordering::SeqCst), 1);
+    assert!(config().worker_threads >= 1);
+}
+
 #[test]
 pub fn rwlock() {
     // This type of lock allows a number of readers or at most one writer at any point in time.
@@ -559,3 +682,635 @@ pub fn mpsc() {
     // Bot 1 : What's your favorite color?
     // Bot 2 : Blue
 }
+
+// The bot chat above pairs two channels by hand so that each question is followed by its answer. RequestResponse
+// bundles that pattern : the requests are sent to a worker thread over one channel and the worker sends the responses
+// back over the other, 'call' sends a request then blocks until its response arrives, so to the caller it looks like
+// a plain (synchronous) function call executed on another thread.
+// Since only one request is in flight at a time, responses can't get out of order.
+pub struct RequestResponse<Req, Resp> {
+    // Both are Options so that Drop can disconnect the requests channel before joining the worker.
+    requests: Option<std::sync::mpsc::Sender<Req>>,
+    responses: std::sync::mpsc::Receiver<Resp>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl<Req: Send + 'static, Resp: Send + 'static> RequestResponse<Req, Resp> {
+    // Spawns the worker thread that answers every request with 'handler' until the RequestResponse is dropped.
+    pub fn spawn<F>(mut handler: F) -> Self
+    where
+        F: FnMut(Req) -> Resp + Send + 'static,
+    {
+        use std::sync::mpsc::channel;
+
+        let (tx_request, rx_request) = channel();
+        let (tx_response, rx_response) = channel();
+
+        let worker = thread::spawn(move || {
+            // Iterating a receiver blocks for each message and ends once the channel disconnects.
+            for request in rx_request {
+                if tx_response.send(handler(request)).is_err() {
+                    break; // the caller is gone, nobody is left to answer.
+                }
+            }
+        });
+
+        RequestResponse {
+            requests: Some(tx_request),
+            responses: rx_response,
+            worker: Some(worker),
+        }
+    }
+
+    // Panics if the worker thread is gone (i.e. the handler panicked).
+    pub fn call(&self, request: Req) -> Resp {
+        self.requests
+            .as_ref()
+            .unwrap() // only ever None while dropping.
+            .send(request)
+            .expect("The worker thread is gone");
+        self.responses.recv().expect("The worker thread is gone")
+    }
+}
+
+impl<Req, Resp> Drop for RequestResponse<Req, Resp> {
+    fn drop(&mut self) {
+        drop(self.requests.take()); // disconnecting the requests channel ends the worker's loop.
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join(); // a panicked worker was already reported by 'call'.
+        }
+    }
+}
+
+#[test]
+pub fn mpsc_request_response() {
+    example_prologue!("mpsc_request_response");
+
+    // The handler is FnMut so it can keep state across the requests, here counting them.
+    let mut handled = 0;
+    let doubler = RequestResponse::spawn(move |n: u64| {
+        handled += 1;
+        println!("Worker doubling {} (request #{})", n, handled);
+        (n * 2, handled)
+    });
+
+    let responses = [1, 21, 500, 0].map(|n| doubler.call(n));
+    println!("Responses : {:?}", responses);
+
+    // Each response matches its request, in the order they were issued.
+    assert_eq!(responses, [(2, 1), (42, 2), (1000, 3), (0, 4)]);
+} // doubler drops here, shutting the worker down and joining it.
+
+// Receives and logs messages until the channel disconnects, returning everything that was received.
+// A channel disconnects once all of its transmitters (including clones) are dropped, at which point
+// recv() stops blocking and returns Err(RecvError) after the already queued messages are drained.
+fn consume_until_disconnect<T: std::fmt::Debug>(rx: std::sync::mpsc::Receiver<T>) -> Vec<T> {
+    let mut received = vec![];
+    loop {
+        match rx.recv() {
+            Ok(msg) => {
+                println!("Received : {:?}", msg);
+                received.push(msg);
+            }
+            Err(e) => {
+                // This is not a failure, it is how the producers signal that they are done.
+                println!("Channel disconnected ({}), the consumer is exiting.", e);
+                break;
+            }
+        }
+    }
+    received
+}
+
+#[test]
+pub fn mpsc_disconnect() {
+    // In the mpsc bot chat example, the loops break on send/recv errors without these errors ever being
+    // intended, here we intentionally shut the channel down by dropping the transmitter.
+
+    example_prologue!("sync_primitives : mpsc disconnect");
+
+    use std::sync::mpsc::channel;
+
+    let (tx, rx) = channel();
+
+    let consumer = thread::spawn(move || consume_until_disconnect(rx));
+
+    tx.send("Hi there!").unwrap();
+    tx.send("How's it going?").unwrap();
+
+    // Dropping the only transmitter disconnects the channel, without this line the consumer would block forever.
+    drop(tx);
+
+    // Joining only succeeds once the consumer has observed the disconnect and exited its loop.
+    let received = consumer.join().unwrap();
+    assert_eq!(received, vec!["Hi there!", "How's it going?"]);
+}
+
+// A select-like multiplexer over two receivers, returning whichever message arrives first on either of them.
+// Blocking on recv() would only let us wait on one channel at a time, so instead we poll both channels via the
+// non-blocking try_recv() and sleep a little between polling rounds to avoid busy-spinning a CPU core.
+// Returns None once both channels are disconnected and drained.
+fn select_recv<T>(
+    rx_1: &std::sync::mpsc::Receiver<T>,
+    rx_2: &std::sync::mpsc::Receiver<T>,
+) -> Option<T> {
+    use std::sync::mpsc::TryRecvError;
+
+    loop {
+        let mut disconnected = 0;
+
+        for rx in [rx_1, rx_2] {
+            match rx.try_recv() {
+                Ok(msg) => return Some(msg),
+                Err(TryRecvError::Empty) => {} // nothing yet, but more might come.
+                Err(TryRecvError::Disconnected) => disconnected += 1,
+            }
+        }
+
+        if disconnected == 2 {
+            return None;
+        }
+
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+pub fn mpsc_select() {
+    example_prologue!("sync_primitives : mpsc select");
+
+    use std::sync::mpsc::channel;
+
+    let (tx_1, rx_1) = channel();
+    let (tx_2, rx_2) = channel();
+
+    // Two producers sending at a different pace on their own channels.
+    let producers = [(tx_1, "Channel1", 3), (tx_2, "Channel2", 5)].map(|(tx, name, pace)| {
+        thread::spawn(move || {
+            for i in 0..5 {
+                tx.send(format!("{}#{}", name, i)).unwrap();
+                thread::sleep(Duration::from_millis(pace));
+            }
+        }) // tx is dropped once the thread is done, disconnecting its channel.
+    });
+
+    let mut received = vec![];
+    while let Some(msg) = select_recv(&rx_1, &rx_2) {
+        println!("Received : {}", msg);
+        received.push(msg);
+    }
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+
+    // Messages of both channels are interleaved, but all of them must have been received.
+    received.sort();
+    let mut expected = (0..5)
+        .flat_map(|i| [format!("Channel1#{}", i), format!("Channel2#{}", i)])
+        .collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(received, expected);
+}
+
+#[test]
+pub fn atomic_shutdown_flag() {
+    // Threads can't be forcibly killed in Rust, instead they have to cooperate in their own cancellation.
+    // A common approach is to share an atomic flag that the worker threads periodically check to know when to exit.
+    // Atomic types (std::sync::atomic) can be safely shared and mutated across threads without a lock.
+
+    example_prologue!("sync_primitives : AtomicBool shutdown flag");
+
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    const WORKERS_N: usize = 4;
+
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let work_done = Arc::new(AtomicUsize::new(0)); // total units of work done by all the workers.
+
+    let thread_handles = (0..WORKERS_N)
+        .map(|i| {
+            let shutdown = Arc::clone(&shutdown);
+            let work_done = Arc::clone(&work_done);
+
+            thread::spawn(move || {
+                // Acquire pairs with the Release store below, so everything the main thread did before
+                // raising the flag is visible to this thread once it sees the flag raised.
+                while !shutdown.load(Ordering::Acquire) {
+                    work_done.fetch_add(1, Ordering::Relaxed); // simulate a unit of work.
+                    thread::sleep(Duration::from_millis(5));
+                }
+                println!("Worker #{} noticed the shutdown flag and exited.", i);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    thread::sleep(Duration::from_millis(50)); // let the workers do some work.
+
+    println!("Raising the shutdown flag.");
+    shutdown.store(true, Ordering::Release);
+
+    // JoinHandle::join can't time out, so we poll is_finished() against a deadline instead.
+    let deadline = Instant::now() + Duration::from_secs(1);
+    while !thread_handles.iter().all(|handle| handle.is_finished()) {
+        assert!(Instant::now() < deadline, "The workers didn't exit in time!");
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    for handle in thread_handles {
+        handle.join().unwrap();
+    }
+
+    println!("Units of work done = {}", work_done.load(Ordering::Relaxed));
+    assert!(work_done.load(Ordering::Relaxed) > 0);
+}
+
+#[test]
+pub fn thread_builder() {
+    // thread::spawn uses the default thread configuration, thread::Builder allows configuring the thread before
+    // spawning it, like giving it a name (shown in panic messages and debuggers) or a custom stack size.
+    // Spawned threads get a 2MB stack by default (overridable via the RUST_MIN_STACK env var), so allocating
+    // a big buffer on their stack would overflow it just like the box_type example (check smart_pointers.rs).
+
+    example_prologue!("thread_builder");
+
+    const BUFFER_SIZE: usize = 8 * 1024 * 1024; // 8MB, way past the default 2MB.
+
+    let handle = thread::Builder::new()
+        .name("big-stack-worker".to_owned())
+        // debug builds may hold a few temporary copies of the buffer on the stack, so leave plenty of room.
+        .stack_size(BUFFER_SIZE * 8)
+        .spawn(|| {
+            let name = thread::current().name().map(str::to_owned);
+            println!("Running on thread {:?}", name);
+
+            // black_box keeps the compiler from optimizing the buffer away, so it really is allocated on the stack.
+            let buffer = std::hint::black_box([1u8; BUFFER_SIZE]);
+            let sum = buffer.iter().map(|&b| b as usize).sum::<usize>();
+
+            (name, sum)
+        })
+        .expect("Failed to spawn the thread"); // unlike thread::spawn, Builder::spawn returns an io::Result.
+
+    let (name, sum) = handle.join().unwrap();
+
+    assert_eq!(name.as_deref(), Some("big-stack-worker"));
+    assert_eq!(sum, BUFFER_SIZE);
+}
+
+#[test]
+pub fn join_all_reports_panics() {
+    example_prologue!("join_all_reports_panics");
+
+    let spawn_workers = |panicking: Option<usize>| {
+        (0..3)
+            .map(|i| {
+                thread::spawn(move || {
+                    if Some(i) == panicking {
+                        panic!("Worker #{} failed!", i);
+                    }
+                    i * 10
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // No panics, the results are returned in the order of the handles.
+    assert_eq!(join_all(spawn_workers(None)).unwrap(), vec![0, 10, 20]);
+
+    // One of the workers panics, the other ones are still joined but only the panic is reported.
+    let panics = join_all(spawn_workers(Some(1))).unwrap_err();
+    assert_eq!(panics.len(), 1);
+
+    // panic! with format arguments produces a String payload.
+    let message = panics[0].downcast_ref::<String>().unwrap();
+    println!("Reported panic : {}", message);
+    assert_eq!(message, "Worker #1 failed!");
+}
+
+// == A global logger ==
+// A static lives for the whole program and is reachable from any thread, which makes it a natural home for a logger.
+// Statics are shared across threads so their type has to be Sync, a Mutex provides that while allowing the state to be
+// mutated through a shared reference (statics can't be borrowed mutably without 'static mut', which is unsafe).
+
+// The variants are declared from the least to the most severe, so the derived ordering compares them by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+struct LoggerState {
+    level: LogLevel, // messages below this level are dropped.
+    records: Vec<(LogLevel, String)>,
+}
+
+pub struct Logger {
+    state: std::sync::Mutex<LoggerState>,
+}
+
+impl Logger {
+    // const so it can initialize a static, Mutex::new and Vec::new are both const fns.
+    pub const fn new() -> Self {
+        Logger {
+            state: std::sync::Mutex::new(LoggerState {
+                level: LogLevel::Debug,
+                records: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn set_level(&self, level: LogLevel) {
+        self.state.lock().unwrap().level = level;
+    }
+
+    // Records the message only if it is at or above the configured level.
+    pub fn log(&self, level: LogLevel, msg: &str) {
+        let mut state = self.state.lock().unwrap();
+        if level >= state.level {
+            println!("[{:?}] {}", level, msg);
+            state.records.push((level, msg.to_owned()));
+        }
+    }
+
+    pub fn records(&self) -> Vec<(LogLevel, String)> {
+        self.state.lock().unwrap().records.clone()
+    }
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub static LOGGER: Logger = Logger::new();
+
+#[test]
+pub fn global_logger() {
+    example_prologue!("global_logger");
+
+    LOGGER.set_level(LogLevel::Info);
+
+    // Every thread logs through the same global logger without having to be handed a reference to it.
+    let handles = (0..4)
+        .map(|i| thread::spawn(move || LOGGER.log(LogLevel::Info, &format!("Hello from thread #{}", i))))
+        .collect::<Vec<_>>();
+    join_all(handles).unwrap();
+
+    LOGGER.log(LogLevel::Debug, "Dropped, below the Info level");
+
+    let records = LOGGER.records();
+    assert_eq!(records.len(), 4);
+    assert!(records.iter().all(|(level, _)| *level == LogLevel::Info));
+}
+
+#[test]
+pub fn logger_level_filter() {
+    example_prologue!("logger_level_filter");
+
+    // A local logger rather than the global one, so that tests running in parallel don't interfere.
+    let logger = Logger::new();
+    logger.set_level(LogLevel::Warn);
+
+    logger.log(LogLevel::Debug, "debug message");
+    logger.log(LogLevel::Info, "info message");
+    logger.log(LogLevel::Warn, "warn message");
+    logger.log(LogLevel::Error, "error message");
+
+    assert_eq!(
+        logger.records(),
+        vec![
+            (LogLevel::Warn, "warn message".to_owned()),
+            (LogLevel::Error, "error message".to_owned()),
+        ]
+    );
+
+    // Lowering the level lets the less severe messages through again.
+    logger.set_level(LogLevel::Debug);
+    logger.log(LogLevel::Debug, "debug message");
+    assert_eq!(logger.records().len(), 3);
+}
+
+// == A concurrent cache ==
+// Wraps the Arc<RwLock<..>> combination from the rwlock example into a reusable type, cloning the cache clones the Arc
+// so every clone handed to a thread shares the same map.
+// Lookups only take the read lock and can run concurrently, inserts take the write lock and are exclusive, which suits
+// a cache that is read a lot more often than it is written to.
+pub struct ConcurrentCache<K, V> {
+    map: std::sync::Arc<std::sync::RwLock<std::collections::HashMap<K, V>>>,
+}
+
+// Implemented by hand rather than derived, #[derive(Clone)] would require K and V to be Clone as well even though
+// only the Arc gets cloned.
+impl<K, V> Clone for ConcurrentCache<K, V> {
+    fn clone(&self) -> Self {
+        ConcurrentCache {
+            map: std::sync::Arc::clone(&self.map),
+        }
+    }
+}
+
+impl<K: Eq + std::hash::Hash, V: Clone> ConcurrentCache<K, V> {
+    pub fn new() -> Self {
+        ConcurrentCache {
+            map: Default::default(),
+        }
+    }
+
+    // Returns a clone of the value, a reference can't outlive the read guard it was borrowed through.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.map.read().unwrap().get(key).cloned()
+    }
+
+    // Returns the value previously cached under 'key' if any.
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        self.map.write().unwrap().insert(key, value)
+    }
+}
+
+impl<K: Eq + std::hash::Hash, V: Clone> Default for ConcurrentCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+pub fn concurrent_cache() {
+    example_prologue!("concurrent_cache");
+
+    const READER_THREADS_N: usize = 4;
+    const ENTRIES_N: u32 = 10;
+
+    let cache = ConcurrentCache::<u32, String>::new();
+    let mut thread_handles = Vec::with_capacity(READER_THREADS_N + 1); // +1 for the writer thread.
+
+    let writer_cache = cache.clone();
+    thread_handles.push(thread::spawn(move || {
+        for i in 0..ENTRIES_N {
+            writer_cache.insert(i, format!("value #{}", i));
+            thread::sleep(Duration::from_millis(1)); // give the readers a chance to grab the lock in between.
+        }
+    }));
+
+    for reader in 0..READER_THREADS_N {
+        let reader_cache = cache.clone();
+        thread_handles.push(thread::spawn(move || {
+            // Spin until the writer's last insert is visible, the entries are inserted in order so all of the
+            // previous ones must be visible by then as well.
+            while reader_cache.get(&(ENTRIES_N - 1)).is_none() {
+                thread::yield_now();
+            }
+            for i in 0..ENTRIES_N {
+                assert_eq!(reader_cache.get(&i), Some(format!("value #{}", i)));
+            }
+            println!("Reader Thread # {} observed all of the {} entries", reader, ENTRIES_N);
+        }));
+    }
+
+    // Every thread finishing means none of them deadlocked, and a failed assertion in a reader surfaces as an error.
+    assert!(join_all(thread_handles).is_ok());
+
+    assert_eq!(cache.insert(0, "replaced".to_owned()), Some("value #0".to_owned()));
+    assert_eq!(cache.get(&0), Some("replaced".to_owned()));
+    assert_eq!(cache.get(&ENTRIES_N), None);
+}
+
+// == Fan-out / fan-in ==
+// The inputs are fanned out to 'workers' threads over a single shared channel, each worker pulling the next input as
+// soon as it's done with the previous one (so a slow input doesn't hold up the others), the mapped results are fanned
+// back in over a second channel and folded by the calling thread into a single value starting from R's default.
+// A Receiver can't be shared between threads as is (it isn't Sync), hence the Arc<Mutex<..>> around it.
+// **Note that the results arrive in whatever order the workers finish in, so 'reduce_fn' should be order independent.
+pub fn map_reduce<T, M, R>(
+    inputs: Vec<T>,
+    workers: usize,
+    map_fn: impl Fn(T) -> M + Send + Sync + 'static,
+    reduce_fn: impl Fn(R, M) -> R,
+) -> R
+where
+    T: Send + 'static,
+    M: Send + 'static,
+    R: Default,
+{
+    use std::sync::mpsc::channel;
+    use std::sync::{Arc, Mutex};
+
+    let (tx_input, rx_input) = channel();
+    let (tx_result, rx_result) = channel();
+
+    // Queue all of the inputs upfront then disconnect the channel, the workers exit once it's drained.
+    for input in inputs {
+        tx_input.send(input).unwrap();
+    }
+    drop(tx_input);
+
+    let rx_input = Arc::new(Mutex::new(rx_input));
+    let map_fn = Arc::new(map_fn);
+
+    let handles = (0..workers.max(1))
+        .map(|_| {
+            let rx_input = Arc::clone(&rx_input);
+            let map_fn = Arc::clone(&map_fn);
+            let tx_result = tx_result.clone();
+            thread::spawn(move || loop {
+                // The lock guard is a temporary dropped at the end of the statement, so the lock is released
+                // before mapping the input and the other workers can pull theirs in the meantime.
+                let input = rx_input.lock().unwrap().recv();
+                match input {
+                    Ok(input) => tx_result.send(map_fn(input)).unwrap(),
+                    Err(_) => break, // no inputs left.
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    drop(tx_result); // only the workers' clones are left, the results channel disconnects once they're all done.
+
+    let result = rx_result.iter().fold(R::default(), reduce_fn);
+    join_all(handles).expect("A worker thread panicked");
+    result
+}
+
+#[test]
+pub fn map_reduce_sum_of_squares() {
+    example_prologue!("map_reduce_sum_of_squares");
+
+    let inputs = (1..=1000u64).collect::<Vec<_>>();
+    let expected = inputs.iter().map(|n| n * n).sum::<u64>();
+
+    let sum = map_reduce(inputs, 4, |n: u64| n * n, |acc: u64, square| acc + square);
+    println!("Sum of the squares of 1..=1000 across 4 workers : {}", sum);
+    assert_eq!(sum, expected);
+
+    // The result type can differ from the mapped one, e.g. collecting the results then sorting them.
+    let mut lengths = map_reduce(vec!["a", "abc", "ab"], 2, |s: &str| s.len(), |mut acc: Vec<usize>, len| {
+        acc.push(len);
+        acc
+    });
+    lengths.sort();
+    assert_eq!(lengths, vec![1, 2, 3]);
+    assert_eq!(map_reduce(Vec::<u64>::new(), 4, |n| n, |acc: u64, n| acc + n), 0);
+}
+
+// == Round-robin load balancing ==
+// Hands out the items (e.g. the senders of worker threads' channels) one after the other, cycling back to the first
+// after the last, so that jobs get spread evenly across the workers.
+// The index is an atomic so that 'next' only needs &self, letting several threads share one balancer (e.g. via an Arc)
+// without a lock, fetch_add guarantees that two concurrent calls are never handed the same turn.
+pub struct RoundRobin<T> {
+    items: Vec<T>,
+    next: std::sync::atomic::AtomicUsize,
+}
+
+impl<T> RoundRobin<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        RoundRobin {
+            items,
+            next: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    // None if there are no items to balance across.
+    pub fn next(&self) -> Option<&T> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let turn = self.next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.items.get(turn % self.items.len())
+    }
+}
+
+#[test]
+pub fn round_robin_workers() {
+    example_prologue!("round_robin_workers");
+
+    use std::sync::mpsc::channel;
+
+    const WORKERS_N: usize = 4;
+
+    // Every worker collects the jobs it receives until its channel disconnects.
+    let (senders, handles): (Vec<_>, Vec<_>) = (0..WORKERS_N)
+        .map(|_| {
+            let (tx, rx) = channel::<usize>();
+            (tx, thread::spawn(move || rx.iter().collect::<Vec<_>>()))
+        })
+        .unzip();
+
+    let balancer = RoundRobin::new(senders);
+    for job in 0..WORKERS_N * 2 {
+        balancer.next().unwrap().send(job).unwrap();
+    }
+    drop(balancer); // drops the senders, disconnecting the workers' channels.
+
+    let received = join_all(handles).unwrap();
+    println!("Jobs received by each worker : {:?}", received);
+
+    // Each worker got exactly two jobs, in turn : worker #0 got jobs 0 and 4, worker #1 jobs 1 and 5 and so on.
+    assert_eq!(received, vec![vec![0, 4], vec![1, 5], vec![2, 6], vec![3, 7]]);
+
+    assert!(RoundRobin::<usize>::new(vec![]).next().is_none());
+}
@@ -124,3 +124,346 @@ pub fn closure_as_argument() {
         open_mystery_box(mystery_box_fn);
     }
 }
+
+// Closures taken as FnMut can be called over and over while keeping their captured state between calls,
+// which makes them a great fit for retrying a fallible operation (check error_handling.rs) until it succeeds.
+
+const RETRY_INITIAL_BACKOFF_MS: u64 = 1; // the sleep duration after the first failed attempt.
+
+// Calls 'f' up to 'max_attempts' times until it returns Ok, sleeping between attempts with a backoff that
+// doubles after every failure. The error of the last attempt is returned if none of the attempts succeeded.
+pub fn retry<F, T, E>(max_attempts: usize, mut f: F) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>, // FnMut since the operation may mutate its captured state on every call.
+{
+    let mut backoff = std::time::Duration::from_millis(RETRY_INITIAL_BACKOFF_MS);
+    let mut attempt = 1;
+
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            // Out of attempts, propagate the last error to the caller.
+            Err(e) if attempt >= max_attempts => return Err(e),
+            Err(_) => {
+                std::thread::sleep(backoff);
+                backoff *= 2; // double the backoff for the next attempt.
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[test]
+pub fn closure_retry() {
+    example_prologue!("closure_retry");
+
+    let mut calls = 0;
+
+    // The closure captures 'calls' by mutable reference, failing the first two calls then succeeding.
+    let result = retry(5, || {
+        calls += 1;
+        println!("Attempt #{}", calls);
+        if calls < 3 {
+            Err("Not yet!")
+        } else {
+            Ok(calls)
+        }
+    });
+
+    assert_eq!(result, Ok(3));
+    assert_eq!(calls, 3);
+}
+
+#[test]
+pub fn closure_retry_exhausted() {
+    let mut calls = 0;
+
+    let result: Result<(), String> = retry(3, || {
+        calls += 1;
+        Err(format!("Failed attempt #{}", calls))
+    });
+
+    assert_eq!(result, Err("Failed attempt #3".to_owned()));
+    assert_eq!(calls, 3);
+}
+
+// Bounds the retrying by time rather than by a number of attempts, 'f' keeps being called until it returns Ok or
+// 'timeout' has elapsed since the first call, in which case the error of the last attempt is returned.
+// Instant is a monotonic clock, unlike SystemTime it never goes backwards which makes it the right tool for measuring
+// elapsed time. 'f' is always called at least once, even with a zero timeout.
+pub fn retry_until<F, T, E>(timeout: std::time::Duration, mut f: F) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    let deadline = std::time::Instant::now() + timeout;
+
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) if std::time::Instant::now() >= deadline => return Err(e),
+            // A short pause keeps the loop from spinning the CPU between attempts.
+            Err(_) => std::thread::sleep(std::time::Duration::from_millis(RETRY_INITIAL_BACKOFF_MS)),
+        }
+    }
+}
+
+#[test]
+pub fn closure_retry_until() {
+    example_prologue!("closure_retry_until");
+
+    let mut calls = 0;
+
+    let result = retry_until(std::time::Duration::from_secs(5), || {
+        calls += 1;
+        println!("Attempt #{}", calls);
+        if calls < 4 {
+            Err("Not yet!")
+        } else {
+            Ok(calls)
+        }
+    });
+
+    assert_eq!(result, Ok(4));
+    assert_eq!(calls, 4);
+}
+
+#[test]
+pub fn closure_retry_until_timeout() {
+    let timeout = std::time::Duration::from_millis(20);
+    let start = std::time::Instant::now();
+    let mut calls = 0;
+
+    let result: Result<(), String> = retry_until(timeout, || {
+        calls += 1;
+        Err(format!("Failed attempt #{}", calls))
+    });
+
+    // Gave up only once the deadline passed, reporting the last error.
+    assert!(start.elapsed() >= timeout);
+    assert!(calls > 1);
+    assert_eq!(result, Err(format!("Failed attempt #{}", calls)));
+}
+
+// Every closure has its own unique anonymous type, so storing different closures in the same collection requires
+// boxing them as trait objects. Since that type gets verbose, a type alias gives it a short readable name.
+// The Send + Sync bounds allow the boxed callbacks to be shared and called across threads.
+pub type BoxedFn = Box<dyn Fn(&str) + Send + Sync>;
+
+// A dispatch table mapping command names to their callbacks.
+#[derive(Default)]
+pub struct Dispatcher {
+    commands: std::collections::HashMap<String, BoxedFn>,
+}
+
+impl Dispatcher {
+    // Registers the callback under the given name, replacing any previously registered one.
+    pub fn register<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static, // 'static since the closure is stored past this call.
+    {
+        self.commands.insert(name.to_owned(), Box::new(f));
+    }
+
+    // Runs the callback registered under 'name' with 'arg', returns false (doing nothing) if there's none.
+    pub fn dispatch(&self, name: &str, arg: &str) -> bool {
+        match self.commands.get(name) {
+            Some(f) => {
+                f(arg);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[test]
+pub fn closure_dispatch_table() {
+    example_prologue!("closure_dispatch_table");
+
+    use std::sync::{Arc, Mutex};
+
+    let log = Arc::new(Mutex::new(Vec::<String>::new()));
+
+    let mut dispatcher = Dispatcher::default();
+
+    let log_ = Arc::clone(&log);
+    dispatcher.register("greet", move |name| {
+        log_.lock().unwrap().push(format!("Hello {}!", name));
+    });
+
+    let log_ = Arc::clone(&log);
+    dispatcher.register("shout", move |text| {
+        log_.lock().unwrap().push(text.to_uppercase());
+    });
+
+    assert!(dispatcher.dispatch("shout", "rust"));
+    assert!(dispatcher.dispatch("greet", "Rust Fans"));
+    assert!(!dispatcher.dispatch("unknown", "ignored")); // no-op.
+
+    println!("Log : {:?}", log.lock().unwrap());
+    assert_eq!(*log.lock().unwrap(), vec!["RUST", "Hello Rust Fans!"]);
+}
+
+// A Vec wrapper firing every registered callback with a reference to each newly pushed value,
+// a mini reactive collection. Unlike BoxedFn above, the callbacks aren't required to be Send + Sync
+// so single threaded closures capturing an Rc<RefCell<..>> (interior mutability) can be registered as well.
+pub type Callback<T> = Box<dyn Fn(&T)>;
+
+pub struct ObservableVec<T> {
+    items: Vec<T>,
+    callbacks: Vec<Callback<T>>,
+}
+
+impl<T> Default for ObservableVec<T> {
+    fn default() -> Self {
+        ObservableVec {
+            items: Vec::new(),
+            callbacks: Vec::new(),
+        }
+    }
+}
+
+impl<T> ObservableVec<T> {
+    pub fn subscribe<F>(&mut self, f: F)
+    where
+        F: Fn(&T) + 'static,
+    {
+        self.callbacks.push(Box::new(f));
+    }
+
+    // Pushes the value then notifies the callbacks in their registration order.
+    pub fn push(&mut self, value: T) {
+        self.items.push(value);
+        let pushed = self.items.last().unwrap();
+        for callback in &self.callbacks {
+            callback(pushed);
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+}
+
+#[test]
+pub fn closure_observable_vec() {
+    example_prologue!("closure_observable_vec");
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // The callbacks are 'Fn' (can't mutate their captures), so the shared buffer is mutated through a RefCell.
+    let recorded = Rc::new(RefCell::new(Vec::new()));
+
+    let mut numbers = ObservableVec::default();
+
+    let recorded_ = Rc::clone(&recorded);
+    numbers.subscribe(move |value: &i32| recorded_.borrow_mut().push(*value));
+
+    numbers.push(1);
+    assert_eq!(*recorded.borrow(), vec![1]);
+
+    numbers.push(2);
+    numbers.push(3);
+    println!("Recorded pushes : {:?}", recorded.borrow());
+    assert_eq!(*recorded.borrow(), vec![1, 2, 3]);
+    assert_eq!(numbers.as_slice(), &[1, 2, 3]);
+}
+
+// Wraps a callback so that it's invoked at most once per 'interval', the events fired before the interval since the
+// last invocation has elapsed are dropped. Handy for events firing in bursts (e.g. key presses or window resizes)
+// when only reacting every so often is enough.
+// The callback is stored as a generic FnMut rather than boxed, the Debouncer owns it and is the only one calling it.
+pub struct Debouncer<F> {
+    callback: F,
+    interval: std::time::Duration,
+    last_call: Option<std::time::Instant>, // None until the first invocation, which always goes through.
+}
+
+impl<F> Debouncer<F> {
+    pub fn new(interval: std::time::Duration, callback: F) -> Self {
+        Debouncer {
+            callback,
+            interval,
+            last_call: None,
+        }
+    }
+
+    // Forwards the event to the callback unless it was invoked less than 'interval' ago, returns whether it was.
+    pub fn fire<T>(&mut self, event: T) -> bool
+    where
+        F: FnMut(T),
+    {
+        let now = std::time::Instant::now();
+        if let Some(last_call) = self.last_call {
+            if now.duration_since(last_call) < self.interval {
+                return false;
+            }
+        }
+        self.last_call = Some(now);
+        (self.callback)(event); // the parentheses are required to call a closure stored in a field.
+        true
+    }
+}
+
+#[test]
+pub fn closure_debouncer() {
+    example_prologue!("closure_debouncer");
+
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    // The callback borrows 'received' which stays readable in between the calls since RefCell checks the borrows
+    // at runtime, only the callback borrows it mutably and only for the duration of a call.
+    let received = RefCell::new(Vec::new());
+    let interval = Duration::from_millis(100);
+    let mut debouncer = Debouncer::new(interval, |key: char| received.borrow_mut().push(key));
+
+    // A burst of events, way quicker than the interval.
+    let fired = "burst".chars().filter(|&key| debouncer.fire(key)).count();
+    println!("Received {:?} out of a burst of 5 events", received.borrow());
+
+    assert!(fired < 5);
+    assert_eq!(fired, received.borrow().len());
+    assert_eq!(received.borrow()[0], 'b'); // the first event always goes through.
+
+    // Once the interval has elapsed the next event goes through again.
+    std::thread::sleep(interval);
+    assert!(debouncer.fire('!'));
+    assert_eq!(received.borrow().last(), Some(&'!'));
+    assert_eq!(received.borrow().len(), fired + 1);
+}
+
+// Splits the items into the ones matching the predicate and the ones that don't, preserving their relative order.
+// The std lib offers the same via Iterator::partition, this manual version shows that there's nothing magical about
+// taking a closure as a parameter: it is called like any other function.
+pub fn partition_by<T, F: Fn(&T) -> bool>(items: Vec<T>, pred: F) -> (Vec<T>, Vec<T>) {
+    let mut matching = vec![];
+    let mut rest = vec![];
+
+    for item in items {
+        if pred(&item) {
+            matching.push(item);
+        } else {
+            rest.push(item);
+        }
+    }
+
+    (matching, rest)
+}
+
+#[test]
+pub fn closure_partition_by() {
+    example_prologue!("closure_partition_by");
+
+    let (evens, odds) = partition_by(vec![7, 2, 9, 4, 4, 1, 6], |n| n % 2 == 0);
+    println!("evens : {:?}, odds : {:?}", evens, odds);
+
+    assert_eq!(evens, vec![2, 4, 4, 6]);
+    assert_eq!(odds, vec![7, 9, 1]);
+
+    // Same result as the std lib version.
+    let (std_evens, std_odds): (Vec<i32>, Vec<i32>) =
+        vec![7, 2, 9, 4, 4, 1, 6].into_iter().partition(|n| n % 2 == 0);
+    assert_eq!((evens, odds), (std_evens, std_odds));
+}
@@ -120,6 +120,58 @@ pub fn iterator_adaptors() {
     // At this point data is longer available since it were consumed by the into_iterator().
 }
 
+// The adaptor chain above is fixed at compile time. A Pipeline builds the chain at runtime instead, the transforms
+// are stored as boxed closures (each closure having its own type, boxing them as trait objects is what allows
+// keeping them in the same Vec) and applied one after the other to every element.
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<Box<dyn Fn(i32) -> i32>>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Appends a transform, taking and returning self so the calls can be chained.
+    pub fn then<F: Fn(i32) -> i32 + 'static>(mut self, stage: F) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    // Runs every element through the stages in the order they were added, an empty pipeline returns the input as is.
+    pub fn run(&self, input: impl IntoIterator<Item = i32>) -> Vec<i32> {
+        input
+            .into_iter()
+            .map(|x| self.stages.iter().fold(x, |acc, stage| stage(acc)))
+            .collect()
+    }
+}
+
+#[test]
+pub fn iterator_pipeline() {
+    example_prologue!("iterator_pipeline");
+
+    let pipeline = Pipeline::new().then(|x| x + 1).then(|x| x * 2);
+
+    let output = pipeline.run(vec![1, 2, 3]);
+    println!("(x + 1) * 2 over [1, 2, 3] = {:?}", output);
+    assert_eq!(output, vec![4, 6, 8]);
+
+    // The order of the stages matters, x * 2 + 1 this time. Any iterator of i32 can be fed in, e.g. a range.
+    let reversed = Pipeline::new().then(|x| x * 2).then(|x| x + 1);
+    assert_eq!(reversed.run(1..=3), vec![3, 5, 7]);
+
+    // The stages can also be picked at runtime and capture their environment like any closure.
+    let offset = 10;
+    let mut dynamic = Pipeline::new();
+    for negate in [true, false] {
+        dynamic = if negate { dynamic.then(|x| -x) } else { dynamic.then(move |x| x + offset) };
+    }
+    assert_eq!(dynamic.run([1, 20]), vec![9, -10]);
+    assert_eq!(Pipeline::new().run([5]), vec![5]);
+}
+
 #[test]
 pub fn custom_iterator() {
     // We can make an iterator out of any struct that implements the Iterator trait.
@@ -162,3 +214,545 @@ pub fn custom_iterator() {
         println!("Current Tick : {}", tick);
     }
 }
+
+// An iterator yielding owned (cloned) chunks of a slice, the last chunk holding the remainder if the
+// slice length isn't divisible by the chunk size. (The std lib offers a borrowing equivalent via slice.chunks())
+pub struct ChunksOwned<'a, T> {
+    data: &'a [T],
+    size: usize,
+}
+
+impl<'a, T: Clone> Iterator for ChunksOwned<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        // split_at panics if the index is out of bounds, hence the min with the remaining length.
+        let (chunk, rest) = self.data.split_at(self.size.min(self.data.len()));
+        self.data = rest;
+        Some(chunk.to_vec())
+    }
+
+    // size_hint is one of the optional Iterator methods, it lets consumers like collect() pre-allocate.
+    // Since we know the exact number of remaining chunks, both bounds are the same.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.data.len().div_ceil(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+// Implementing ExactSizeIterator (which relies on an exact size_hint) provides the len() method.
+impl<'a, T: Clone> ExactSizeIterator for ChunksOwned<'a, T> {}
+
+// Panics if 'size' is 0 since the iteration would never end.
+pub fn chunks_owned<T: Clone>(data: &[T], size: usize) -> impl ExactSizeIterator<Item = Vec<T>> + '_ {
+    assert!(size != 0, "chunk size must be non-zero");
+    ChunksOwned { data, size }
+}
+
+#[test]
+pub fn chunks_owned_even() {
+    example_prologue!("chunks_owned");
+
+    let data = [1, 2, 3, 4, 5, 6];
+    let mut chunks = chunks_owned(&data, 2);
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks.next(), Some(vec![1, 2]));
+    assert_eq!(chunks.len(), 2); // the length shrinks as the iterator gets consumed.
+    assert_eq!(chunks.collect::<Vec<_>>(), vec![vec![3, 4], vec![5, 6]]);
+}
+
+#[test]
+pub fn chunks_owned_remainder() {
+    let data = ["a", "b", "c", "d", "e"];
+    let chunks = chunks_owned(&data, 2);
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(
+        chunks.collect::<Vec<_>>(),
+        vec![vec!["a", "b"], vec!["c", "d"], vec!["e"]]
+    );
+}
+
+#[test]
+pub fn chunks_owned_larger_than_slice() {
+    let data = [1, 2, 3];
+
+    assert_eq!(chunks_owned(&data, 10).collect::<Vec<_>>(), vec![vec![1, 2, 3]]);
+    assert_eq!(chunks_owned::<i32>(&[], 10).len(), 0);
+}
+
+// An iterator yielding every overlapping window of 'size' consecutive elements of a slice, e.g. windows of 2 over
+// [1, 2, 3] yields [1, 2] then [2, 3]. Unlike ChunksOwned above the windows borrow from the slice, so the yielded
+// items carry the slice's lifetime 'a. (The std lib offers the same via slice.windows())
+pub struct Windows<'a, T> {
+    data: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size > self.data.len() {
+            return None;
+        }
+        let window = &self.data[..self.size];
+        self.data = &self.data[1..]; // slide by a single element, hence the overlap.
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.data.len() + 1).saturating_sub(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+// Panics if 'size' is 0 since an empty window could be yielded forever.
+pub fn windows_custom<T>(slice: &[T], size: usize) -> Windows<'_, T> {
+    assert!(size != 0, "window size must be non-zero");
+    Windows { data: slice, size }
+}
+
+#[test]
+pub fn custom_windows() {
+    example_prologue!("custom_windows");
+
+    let data = [1, 2, 3, 4];
+    let windows = windows_custom(&data, 2).collect::<Vec<_>>();
+    println!("Windows of 2 over {:?} : {:?}", data, windows);
+
+    assert_eq!(windows, vec![&[1, 2][..], &[2, 3], &[3, 4]]);
+    assert_eq!(windows_custom(&data, 2).size_hint(), (3, Some(3)));
+    // Same as the std lib version.
+    assert!(windows_custom(&data, 3).eq(data.windows(3)));
+}
+
+#[test]
+pub fn custom_windows_larger_than_slice() {
+    assert_eq!(windows_custom(&[1, 2, 3], 4).next(), None);
+    assert_eq!(windows_custom(&[1, 2, 3], 4).size_hint(), (0, Some(0)));
+    assert_eq!(windows_custom::<i32>(&[], 1).count(), 0);
+    assert_eq!(windows_custom(&[1, 2, 3], 3).count(), 1);
+}
+
+// Any iterator can be turned into a Peekable iterator via the peekable() adaptor, which adds the peek() method
+// allowing us to look at the next item without consuming it. This is a perfect fit for parsing, where we often
+// need to decide what to do based on the upcoming character before actually consuming it.
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Token {
+    Number(i64),
+    Plus,
+    Minus,
+    Invalid(char), // any character that isn't part of the arithmetic grammar.
+    Overflow,      // a number literal too large to fit in an i64.
+}
+
+// Tokenizes a simple arithmetic string made of numbers and +/- operators, whitespace is skipped.
+pub fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '+' => tokens.push(Token::Plus),
+            '-' => tokens.push(Token::Minus),
+            c if c.is_whitespace() => {}
+            '0'..='9' => {
+                // A number can span multiple digits, keep consuming chars as long as the next one is a digit.
+                // The checked operations return None rather than overflowing, which sticks for the rest of the digits.
+                let mut number = Some(c.to_digit(10).unwrap() as i64);
+                while let Some(digit) = chars.peek().and_then(|next| next.to_digit(10)) {
+                    number = number
+                        .and_then(|n| n.checked_mul(10))
+                        .and_then(|n| n.checked_add(digit as i64));
+                    chars.next(); // only consume the digit now that we know it belongs to the number.
+                }
+                tokens.push(number.map_or(Token::Overflow, Token::Number));
+            }
+            c => tokens.push(Token::Invalid(c)),
+        }
+    }
+
+    tokens
+}
+
+#[test]
+pub fn peekable_tokenizer() {
+    example_prologue!("peekable_tokenizer");
+
+    let tokens = tokenize("12+34-5");
+    println!("Tokens of \"12+34-5\" : {:?}", tokens);
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Number(12),
+            Token::Plus,
+            Token::Number(34),
+            Token::Minus,
+            Token::Number(5),
+        ]
+    );
+}
+
+#[test]
+pub fn peekable_tokenizer_whitespace_and_invalid() {
+    assert_eq!(
+        tokenize(" 7 + 100 * 2"),
+        vec![
+            Token::Number(7),
+            Token::Plus,
+            Token::Number(100),
+            Token::Invalid('*'),
+            Token::Number(2),
+        ]
+    );
+    assert!(tokenize("").is_empty());
+}
+
+// Evaluates the tokens of a simple arithmetic expression (e.g. 12+34-5) from left to right.
+// The grammar is a number followed by any number of operator + number pairs, anything else is an error.
+// The parser pulls the tokens one at a time out of the token iterator, so it is generic over any iterator
+// of tokens, not just a Vec.
+pub struct Parser<I: Iterator<Item = Token>> {
+    tokens: I,
+}
+
+impl<I: Iterator<Item = Token>> Parser<I> {
+    pub fn new(tokens: impl IntoIterator<Item = Token, IntoIter = I>) -> Self {
+        Parser {
+            tokens: tokens.into_iter(),
+        }
+    }
+
+    // Consumes the parser, returning the result of the expression or a description of what went wrong.
+    pub fn evaluate(mut self) -> Result<i64, String> {
+        let mut result = self.expect_number()?;
+
+        while let Some(operator) = self.tokens.next() {
+            if !matches!(operator, Token::Plus | Token::Minus) {
+                return Err(format!("Expected an operator but found {:?}", operator));
+            }
+
+            let operand = self.expect_number()?;
+            result = match operator {
+                Token::Plus => result.checked_add(operand),
+                _ => result.checked_sub(operand),
+            }
+            .ok_or_else(|| String::from("The expression overflowed"))?;
+        }
+
+        Ok(result)
+    }
+
+    fn expect_number(&mut self) -> Result<i64, String> {
+        match self.tokens.next() {
+            Some(Token::Number(n)) => Ok(n),
+            Some(Token::Overflow) => Err(String::from("The expression overflowed")),
+            Some(token) => Err(format!("Expected a number but found {:?}", token)),
+            None => Err(String::from("Expected a number but reached the end of the input")),
+        }
+    }
+}
+
+// Convenience function tying the tokenizer and the parser together.
+pub fn evaluate(input: &str) -> Result<i64, String> {
+    Parser::new(tokenize(input)).evaluate()
+}
+
+#[test]
+pub fn parser_evaluate() {
+    example_prologue!("parser_evaluate");
+
+    let result = evaluate("12+34-5");
+    println!("12+34-5 = {:?}", result);
+
+    assert_eq!(result, Ok(41));
+    assert_eq!(evaluate(" 7 "), Ok(7));
+    assert_eq!(evaluate("1 - 2 - 3"), Ok(-4)); // left to right, (1 - 2) - 3.
+}
+
+#[test]
+pub fn parser_malformed_input() {
+    example_prologue!("parser_malformed_input");
+
+    let result = evaluate("12+");
+    println!("12+ = {:?}", result);
+
+    assert_eq!(
+        result,
+        Err(String::from("Expected a number but reached the end of the input"))
+    );
+    assert_eq!(
+        evaluate("12 34"),
+        Err(String::from("Expected an operator but found Number(34)"))
+    );
+    assert_eq!(
+        evaluate("2*3"),
+        Err(String::from("Expected an operator but found Invalid('*')"))
+    );
+    assert!(evaluate("").is_err());
+}
+
+#[test]
+pub fn parser_literal_overflow() {
+    example_prologue!("parser_literal_overflow");
+
+    // 20 digits, way past i64::MAX (9223372036854775807, 19 digits).
+    assert_eq!(tokenize("99999999999999999999"), vec![Token::Overflow]);
+    assert_eq!(
+        evaluate("1 + 99999999999999999999"),
+        Err(String::from("The expression overflowed"))
+    );
+
+    // i64::MAX itself still fits.
+    assert_eq!(evaluate("9223372036854775807"), Ok(i64::MAX));
+}
+
+// Splits a CSV-like line into its fields. Commas separate the fields unless they appear inside a double-quoted field,
+// where a doubled quote ("") stands for a literal quote, e.g. a,"b, ""c""" has the two fields a and b, "c".
+// Like the tokenizer above, it walks a Peekable over the chars, peeking is what tells an escaped quote apart from
+// the closing one. Whether the walk is inside quotes or not is the state that changes how each char is treated.
+pub fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) => {
+                if chars.peek() == Some(&'"') {
+                    chars.next(); // "" is an escaped quote, skip the second one.
+                    field.push('"');
+                } else {
+                    in_quotes = false; // closing quote.
+                }
+            }
+            ('"', false) => in_quotes = true,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            (c, _) => field.push(c),
+        }
+    }
+    fields.push(field); // the last field isn't followed by a comma.
+
+    fields
+}
+
+#[test]
+pub fn csv_simple_line() {
+    example_prologue!("csv_simple_line");
+
+    let fields = parse_csv_line("AK47,3000,rifle");
+    println!("Fields : {:?}", fields);
+
+    assert_eq!(fields, vec!["AK47", "3000", "rifle"]);
+    // Empty fields are kept.
+    assert_eq!(parse_csv_line("a,,b,"), vec!["a", "", "b", ""]);
+}
+
+#[test]
+pub fn csv_quoted_field_with_comma() {
+    example_prologue!("csv_quoted_field_with_comma");
+
+    let fields = parse_csv_line(r#"P90,"SMG, high capacity",2350"#);
+    println!("Fields : {:?}", fields);
+
+    assert_eq!(fields, vec!["P90", "SMG, high capacity", "2350"]);
+}
+
+#[test]
+pub fn csv_escaped_quote() {
+    example_prologue!("csv_escaped_quote");
+
+    let fields = parse_csv_line(r#"FAMAS,"The ""Clairon"" rifle""#);
+    println!("Fields : {:?}", fields);
+
+    assert_eq!(fields, vec!["FAMAS", r#"The "Clairon" rifle"#]);
+    assert_eq!(parse_csv_line(r#""""#), vec![""]);
+}
+
+// fold is the most general consuming adaptor: it takes an initial accumulator value and a closure that combines
+// the accumulator with every item, returning the final accumulator. (sum and product are special cases of fold)
+#[allow(clippy::unnecessary_fold)] // product() would do, but the point here is to demonstrate fold.
+pub fn factorial(n: u64) -> u64 {
+    (1..=n).fold(1, |acc, x| acc * x)
+}
+
+// scan is the lazy counterpart of fold: it holds a mutable state between iterations and yields a value on every
+// iteration, which makes it produce the sequence of intermediate (partial) results instead of only the final one.
+pub fn partial_products(n: u64) -> Vec<u64> {
+    (1..=n)
+        .scan(1, |state, x| {
+            *state *= x; // update the running product.
+            Some(*state) // yield it, returning None would end the iteration early.
+        })
+        .collect()
+}
+
+#[test]
+#[allow(clippy::unnecessary_fold)]
+pub fn fold_and_scan() {
+    example_prologue!("fold_and_scan");
+
+    let product = (1..=5).fold(1, |acc, x| acc * x);
+    println!("fold : 1 * 2 * 3 * 4 * 5 = {}", product);
+    assert_eq!(product, 120);
+    assert_eq!(factorial(5), 120);
+    assert_eq!(factorial(0), 1); // folding an empty range returns the initial value.
+
+    let partials = partial_products(5);
+    println!("scan : partial products = {:?}", partials);
+    assert_eq!(partials, vec![1, 2, 6, 24, 120]);
+    assert_eq!(partials.last(), Some(&factorial(5)));
+}
+
+// try_fold is the fallible counterpart of fold: the closure returns an Option (or a Result), and the iteration
+// stops right away at the first None (or Err) which is then returned, otherwise the final accumulator is returned
+// wrapped in Some (or Ok). Combined with checked_add it makes for a sum that can't silently overflow.
+pub fn checked_sum(items: &[u32]) -> Option<u32> {
+    items.iter().try_fold(0u32, |acc, &x| acc.checked_add(x))
+}
+
+#[test]
+pub fn try_fold_checked_sum() {
+    example_prologue!("try_fold_checked_sum");
+
+    assert_eq!(checked_sum(&[1, 2, 3, 4]), Some(10));
+    assert_eq!(checked_sum(&[]), Some(0));
+    assert_eq!(checked_sum(&[u32::MAX, 0]), Some(u32::MAX)); // right at the limit is fine.
+
+    // u32::MAX - 1 + 1 fits, adding another 1 overflows.
+    let overflowing = [u32::MAX - 1, 1, 1, 5];
+    println!("checked_sum({:?}) = {:?}", overflowing, checked_sum(&overflowing));
+    assert_eq!(checked_sum(&overflowing), None);
+}
+
+// flat_map maps every item into an iterable and flattens the results into a single iterator,
+// here each inner vector gets turned into an iterator of its own items.
+pub fn flatten(nested: Vec<Vec<i32>>) -> Vec<i32> {
+    let flattened = nested.into_iter().flat_map(|inner| inner.into_iter()).collect::<Vec<_>>();
+    println!("flat_map : {:?}", flattened);
+    flattened
+}
+
+// take_while yields items as long as the predicate holds and stops at the first item that fails it,
+// skip_while does the opposite by skipping items until the predicate fails then yielding all the rest.
+// **Note that neither resumes checking the predicate afterwards, so they're mostly useful over sorted data.
+pub fn split_while_below(range: std::ops::Range<i32>, limit: i32) -> (Vec<i32>, Vec<i32>) {
+    let below = range.clone().take_while(|&x| x < limit).collect::<Vec<_>>();
+    let rest = range.skip_while(|&x| x < limit).collect::<Vec<_>>();
+    println!("take_while : {:?}, skip_while : {:?}", below, rest);
+    (below, rest)
+}
+
+#[test]
+pub fn flat_map_and_while_adaptors() {
+    example_prologue!("flat_map_and_while_adaptors");
+
+    assert_eq!(
+        flatten(vec![vec![1, 2], vec![], vec![3], vec![4, 5, 6]]),
+        vec![1, 2, 3, 4, 5, 6]
+    );
+
+    let (below, rest) = split_while_below(0..10, 3);
+    assert_eq!(below, vec![0, 1, 2]);
+    assert_eq!(rest, vec![3, 4, 5, 6, 7, 8, 9]);
+
+    // On unsorted data take_while stops at the first failing item even if later items pass.
+    assert_eq!(
+        [1, 5, 2].iter().take_while(|&&x| x < 3).collect::<Vec<_>>(),
+        vec![&1]
+    );
+}
+
+// Iterator adaptor chains have long unnameable types (the closures' types are anonymous), e.g.
+// Map<Filter<Range<u32>, [closure]>, [closure]>. Returning 'impl Iterator' hides that concrete type behind an
+// opaque one, the caller only knows it gets some iterator of u64 items. It stays lazy: nothing is computed until
+// the caller consumes it, and it costs nothing at runtime unlike returning a Box<dyn Iterator>.
+// The squares are u64 since squaring a u32 past 65535 overflows a u32, while any u32 squared fits in a u64.
+pub fn even_squares(limit: u32) -> impl Iterator<Item = u64> {
+    (0..limit).filter(|n| n % 2 == 0).map(|n| n as u64 * n as u64)
+}
+
+#[test]
+pub fn returning_impl_iterator() {
+    example_prologue!("returning_impl_iterator");
+
+    let squares = even_squares(10); // nothing has been computed yet.
+    let squares = squares.collect::<Vec<_>>();
+    println!("Squares of the even numbers below 10 : {:?}", squares);
+    assert_eq!(squares, vec![0, 4, 16, 36, 64]);
+
+    // Since it is lazy, only what's consumed gets computed, even out of a huge range.
+    assert_eq!(even_squares(u32::MAX).nth(3), Some(36));
+
+    // Consuming past 65535, whose square is the last one to fit in a u32.
+    assert_eq!(even_squares(u32::MAX).nth(32768), Some(65536 * 65536)); // the square of 65536, 2^32.
+    assert_eq!(even_squares(70_000).last(), Some(69_998 * 69_998));
+}
+
+// Run-length encoding compresses the runs of a repeated character into a single (char, run length) pair,
+// e.g. "aaab" into [('a', 3), ('b', 1)]. The encoder walks a Peekable over the chars (check the tokenizer above),
+// counting how many of the following chars are the same as the current one by consuming them via next_if_eq.
+pub fn rle_encode(input: &str) -> Vec<(char, usize)> {
+    let mut encoded = vec![];
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let mut run = 1;
+        while chars.next_if_eq(&c).is_some() {
+            run += 1;
+        }
+        encoded.push((c, run));
+    }
+
+    encoded
+}
+
+// The reverse, expanding every pair back into its run. repeat_n(c, n) is an iterator of n times c,
+// flat_map concatenates the runs into a single iterator of chars collected into the String.
+pub fn rle_decode(encoded: &[(char, usize)]) -> String {
+    encoded
+        .iter()
+        .flat_map(|&(c, run)| std::iter::repeat_n(c, run))
+        .collect()
+}
+
+#[test]
+pub fn rle_long_runs() {
+    example_prologue!("rle_long_runs");
+
+    let input = "aaaaaaaaaabbbbbcccccccccccccccccccca";
+    let encoded = rle_encode(input);
+    println!("{} is encoded into {:?}", input, encoded);
+
+    assert_eq!(encoded, vec![('a', 10), ('b', 5), ('c', 20), ('a', 1)]);
+    assert_eq!(rle_decode(&encoded), input);
+}
+
+#[test]
+pub fn rle_single_chars() {
+    example_prologue!("rle_single_chars");
+
+    // Without any repetition every char gets a run of 1, the encoding is longer than the input.
+    assert_eq!(rle_encode("abc"), vec![('a', 1), ('b', 1), ('c', 1)]);
+    assert_eq!(rle_decode(&rle_encode("abc")), "abc");
+
+    // Runs of multi byte chars work just the same since the encoder works on chars rather than bytes.
+    assert_eq!(rle_encode("x🦀🦀"), vec![('x', 1), ('🦀', 2)]);
+    assert_eq!(rle_decode(&[('x', 1), ('🦀', 2)]), "x🦀🦀");
+}
+
+#[test]
+pub fn rle_empty() {
+    example_prologue!("rle_empty");
+
+    assert!(rle_encode("").is_empty());
+    assert_eq!(rle_decode(&[]), "");
+}
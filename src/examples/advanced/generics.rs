@@ -131,3 +131,176 @@ pub fn where_clause() {
         largest(&n, std::io::stdout());
     }
 }
+
+// A variant of the where_clause 'largest' function above which, rather than unwrapping the result of the write!
+// macro (panicking on failure), propagates any write error to the caller via the '?' operator (check error_handling.rs).
+// The writer is taken by mutable reference so that the caller gets to use it afterwards (e.g. read a buffer back).
+pub fn largest_logged<T, W>(list: &[T], writer: &mut W) -> std::io::Result<T>
+where
+    T: std::cmp::PartialOrd + Copy + std::fmt::Display,
+    W: std::io::Write,
+{
+    // An empty list has no largest item, report it as an error rather than panicking on list[0].
+    let Some(&first) = list.first() else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Can't find the largest item of an empty list",
+        ));
+    };
+    let mut largest = first;
+
+    for &item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+
+    writeln!(writer, "{}", largest)?; // returns early with the error if the write fails.
+
+    Ok(largest)
+}
+
+#[test]
+pub fn where_clause_error_propagation() {
+    example_prologue!("where_clause_error_propagation");
+
+    // Vec<u8> implements the Write trait, which makes it a handy in-memory buffer to write into.
+    let mut buffer: Vec<u8> = Vec::new();
+
+    let result = largest_logged(&[34, 50, 25, 100, 65], &mut buffer);
+    assert_eq!(result.unwrap(), 100);
+
+    let result = largest_logged(&['y', 'm', 'a', 'q'], &mut buffer);
+    assert_eq!(result.unwrap(), 'y');
+
+    assert_eq!(String::from_utf8(buffer).unwrap(), "100\ny\n");
+
+    // A fixed size slice buffer implements Write as well, but fails once it runs out of space.
+    let mut small_buffer = [0u8; 2];
+    let result = largest_logged(&[1000, 5], &mut &mut small_buffer[..]);
+    println!("Writing into a 2 bytes buffer : {:?}", result);
+    assert!(result.is_err());
+}
+
+#[test]
+pub fn where_clause_empty_list() {
+    example_prologue!("where_clause_empty_list");
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let error = largest_logged::<i32, _>(&[], &mut buffer).unwrap_err();
+    println!("Empty list : {}", error);
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(buffer.is_empty()); // nothing was written.
+}
+
+// Generics aren't limited to types, const generics let us be generic over constant values as well, like the
+// N capacity below (check out the FixedSizedStack in unsafe_ops.rs for a heap backed example).
+// A RingBuffer (circular buffer) is a fixed capacity FIFO queue that overwrites its oldest elements once full,
+// it is fully stack allocated here, and safe since every slot is an Option rather than uninitialized memory.
+pub struct RingBuffer<T, const N: usize> {
+    slots: [Option<T>; N],
+    head: usize, // index of the oldest element (the front).
+    len: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub fn new() -> Self {
+        RingBuffer {
+            // [None; N] would require T to be Copy, from_fn builds the array by calling the closure per slot.
+            slots: std::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    // Pushes to the back, returning the overwritten oldest element if the buffer was full.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if N == 0 {
+            return Some(value); // no room at all, the value is "overwritten" right away.
+        }
+
+        if self.len == N {
+            // Full, the back wraps around onto the front slot, so the front moves forward by one.
+            let overwritten = self.slots[self.head].replace(value);
+            self.head = (self.head + 1) % N;
+            overwritten
+        } else {
+            let back = (self.head + self.len) % N;
+            self.slots[back] = Some(value);
+            self.len += 1;
+            None
+        }
+    }
+
+    // Pops from the front (the oldest element), None if empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.slots[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+pub fn const_generic_ring_buffer() {
+    example_prologue!("const_generic_ring_buffer");
+
+    let mut ring = RingBuffer::<i32, 3>::new();
+
+    for i in 1..=3 {
+        assert_eq!(ring.push(i), None);
+    }
+    assert_eq!(ring.len(), 3);
+
+    // The buffer is full, pushing overwrites the oldest elements.
+    assert_eq!(ring.push(4), Some(1));
+    assert_eq!(ring.push(5), Some(2));
+    assert_eq!(ring.len(), 3);
+
+    // Pop order is FIFO, from the oldest remaining element.
+    let mut popped = vec![];
+    while let Some(value) = ring.pop() {
+        popped.push(value);
+    }
+    println!("Popped {:?}", popped);
+    assert_eq!(popped, vec![3, 4, 5]);
+    assert!(ring.is_empty());
+}
+
+#[test]
+pub fn const_generic_ring_buffer_wrap_around() {
+    let mut ring = RingBuffer::<String, 2>::default();
+
+    // Interleave pushes and pops so that the front and back wrap around the array several times.
+    for i in 0..10 {
+        ring.push(i.to_string());
+        if i % 2 == 1 {
+            assert_eq!(ring.pop(), Some((i - 1).to_string()));
+        }
+    }
+    assert_eq!(ring.len(), 1);
+    assert_eq!(ring.pop(), Some("9".to_string()));
+    assert_eq!(ring.pop(), None);
+
+    let mut empty = RingBuffer::<u8, 0>::new();
+    assert_eq!(empty.push(1), Some(1));
+    assert_eq!(empty.pop(), None);
+}
@@ -186,3 +186,45 @@ pub fn lifetime_elision() {
     manual.multiple_inputs_with_self_setter("content_1", "content_2", "content_3");
     
 }
+
+#[test]
+pub fn struct_multiple_lifetimes() {
+
+    example_prologue!("struct_multiple_lifetimes");
+
+    // A struct can take in more than one lifetime parameter when it holds references that don't necessarily
+    // live for the same duration. Most of the time a single shared lifetime is enough, since the compiler will
+    // simply pick the shorter of the two lifetimes, but that shortening can get in the way as shown below.
+
+    struct Pair<'a, 'b> {
+        left: &'a str,
+        right: &'b str,
+    }
+
+    impl<'a, 'b> Pair<'a, 'b> {
+        // The returned reference is tied to 'a only, it isn't bound to how long 'right' lives.
+        fn left(&self) -> &'a str {
+            self.left
+        }
+    }
+
+    let left = String::from("Long lived");
+
+    let result;
+    {
+        let right = String::from("Short lived");
+        let pair = Pair {
+            left: left.as_str(),
+            right: right.as_str(),
+        };
+        println!("Pair = ({}, {})", pair.left, pair.right);
+
+        result = pair.left();
+    } // 'right' is dropped here.
+
+    // 'result' is still valid after 'right' got dropped because it only borrows from 'left'.
+    // Had Pair been declared with a single lifetime (struct Pair<'a> { left: &'a str, right: &'a str })
+    // 'a would have been shortened to the scope of 'right', and the following line wouldn't compile.
+    println!("Left of the pair = {}", result);
+    assert_eq!(result, "Long lived");
+}
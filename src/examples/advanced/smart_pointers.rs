@@ -1,6 +1,10 @@
 // Book Ref : https://doc.rust-lang.org/book/ch15-00-smart-pointers.html
 
-use std::ops::DerefMut;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::ops::{Deref, DerefMut};
+use std::rc::{Rc, Weak};
 
 use crate::util::*;
 use crate::*; //Import the entire crate.
@@ -59,6 +63,32 @@ pub fn box_type() {
     // Right after this scope exits, the BigStruct instance will be dropped and the memory related to it will be freed.
 }
 
+#[test]
+pub fn deref_coercion_chain() {
+    example_prologue!("deref_coercion_chain");
+
+    // Deref coercion isn't limited to a single step, the compiler keeps inserting derefs as many times as
+    // needed until the reference type matches what the function expects (all resolved at compile time, no runtime cost).
+    fn char_count(s: &str) -> usize {
+        s.chars().count()
+    }
+
+    let boxed: Box<Box<String>> = Box::new(Box::new(String::from("Hello")));
+    print_type_of("Type of boxed =", &boxed); // Box<Box<String>>
+
+    // Passing &Box<Box<String>> where a &str is expected takes 3 coercion steps:
+    // &Box<Box<String>> -> &Box<String> (Box's Deref)
+    // &Box<String>      -> &String      (Box's Deref)
+    // &String           -> &str         (String's Deref)
+    assert_eq!(char_count(&boxed), 5);
+
+    // Which is what we would otherwise have to spell out explicitly, **boxed being the String.
+    assert_eq!(char_count((**boxed).as_str()), char_count("Hello"));
+
+    // Method calls go through the same chain (auto-deref), so str methods are callable directly on the box.
+    assert!(boxed.starts_with("He"));
+}
+
 #[test]
 pub fn rc_type() {
     // the Reference Counted Smart Pointer
@@ -159,6 +189,145 @@ pub fn refcell_type() {
     msg_box.push_message(String::from("Hello World from GoodMessageBox"));
 }
 
+// The Node used by the weak_type example, declared at the module level so that it can be used by other examples as well.
+// A Node can have multiple parents and children, parents own (Rc) their children while children only weakly
+// reference (Weak) their parents to avoid circular references.
+// The node is generic over the data it carries, the weak_type example uses String names as the node data.
+#[derive(Debug)]
+pub struct Node<T> {
+    data: T,                             // data carried by the node
+    parent: RefCell<Vec<Weak<Node<T>>>>, // An interiorly mutable vector of weakly referenced parent nodes
+    children: RefCell<Vec<Rc<Node<T>>>>, // An interiorly mutable vector of strongly referenced children nodes
+}
+
+impl<T> Node<T> {
+    pub fn new(data: T) -> Rc<Node<T>> {
+        // Factory method.
+        Rc::new(Node {
+            data,
+            parent: RefCell::new(vec![]),
+            children: RefCell::new(vec![]),
+        })
+    }
+
+    // Creates a new strong referenced child node,  adds a clone to itself to its parent,
+    // adds a weak referenced (downgraded version) of its parents to itself, and finally
+    // returns a downgraded version of itself.
+
+    pub fn add_child(parents: &Vec<Rc<Node<T>>>, data: T) -> Weak<Node<T>> {
+        let child = Node::new(data); //Create a new strong ref'd child node.
+
+        // Iterate its strong ref'd parent nodes.
+        parents.iter().for_each(|parent| {
+            //downgrade the parent to a weak ref and add it to itself.
+            child.parent.borrow_mut().push(Rc::downgrade(parent));
+            // Add a clone of itself to its strong ref'd parent.
+            parent.children.borrow_mut().push(child.clone())
+        });
+
+        return Rc::<Node<T>>::downgrade(&child); // return a downgraded version of itself (weak ref)
+    }
+
+    // Reports whether a cycle of strong references is reachable from this node by walking the children.
+    // Such a cycle is a memory leak since the nodes of the cycle keep each other alive (their strong count never reaches 0).
+    // A node being reachable through multiple paths (e.g. a leaf shared by two parents) isn't a cycle, so rather than
+    // merely tracking the visited nodes we track the nodes on the path currently being walked, a cycle exists if
+    // we reach a node that is already on that path. Nodes are identified by their address.
+    pub fn has_cycle(&self) -> bool {
+        fn visit<T>(
+            node: &Node<T>,
+            on_path: &mut HashSet<*const Node<T>>,
+            done: &mut HashSet<*const Node<T>>,
+        ) -> bool {
+            let address = node as *const Node<T>;
+            if on_path.contains(&address) {
+                return true; // we walked back into a node we came from.
+            }
+            if !done.insert(address) {
+                return false; // already fully walked through another path without finding a cycle.
+            }
+
+            on_path.insert(address);
+            let found = node
+                .children
+                .borrow()
+                .iter()
+                .any(|child| visit(child, on_path, done));
+            on_path.remove(&address);
+            found
+        }
+
+        visit(self, &mut HashSet::new(), &mut HashSet::new())
+    }
+}
+
+// The printing methods only require the node data to be printable (implement the Display trait).
+impl<T: Display> Node<T> {
+    pub fn print_tree(&self, recur_count: usize) {
+        println!(
+            " {} [{:?}] child of {:?}",
+            self.data,        // data of node
+            self as *const _, // address of node (debug formatter prints it in hex)
+            // Map the parent nodes into a collection of strings (parent data).
+            self.parent
+                .borrow() // we borrow since its RefCell wrapped.
+                .iter() // We get the iterator.
+                // We attempt upgrade weakly ref'd parent nodes to strong ref'd ones, in
+                // case the parent still lives in memory, we map its data otherwise we
+                // map a defaulted "None". (perhaps the parent was destroyed but child node's
+                // ownership still shared with other parents).
+                .map(|p| p
+                    .upgrade() //Try upgrading to strong ref.
+                    .map_or(String::from("None"), |p| p.data.to_string())) // format its data if avail or default to "None"
+                .collect::<Vec<String>>() // collect it into a vector of strings that we can print.
+        );
+
+        //Iterate the children nodes recursively and tab out their print in multitude of recur_count.
+        for child in self.children.borrow().iter() {
+            for _ in 0..recur_count {
+                print!("\t"); // create a tabbed indentation * recur_count
+            }
+            child.print_tree(recur_count + 1);
+        }
+    }
+
+    // print_tree above recurses once per tree level, so a deep enough tree would overflow the stack.
+    // This traversal visits the nodes in the same (depth first) order but keeps track of the nodes
+    // left to visit in an explicit heap allocated Vec used as a stack instead of the call stack.
+    pub fn iter_depth_first(&self) -> Vec<String> {
+        let mut names = vec![];
+        let mut stack: Vec<Rc<Node<T>>> = vec![];
+
+        names.push(self.data.to_string());
+        // Push the children in reverse so that the first child ends up on top of the stack and gets visited first.
+        stack.extend(self.children.borrow().iter().rev().cloned());
+
+        while let Some(node) = stack.pop() {
+            names.push(node.data.to_string());
+            stack.extend(node.children.borrow().iter().rev().cloned());
+        }
+
+        names
+    }
+}
+
+// A free function version of the tree printing, decoupled from the node internals (parents, addresses)
+// and only printing the node data indented by its depth in the tree.
+pub fn print_tree<T: Display>(root: &Node<T>, indent: usize) {
+    print!("{}", format_tree(root, indent));
+}
+
+// Formats the tree into a String rather than printing it right away, which makes the output easy to test.
+pub fn format_tree<T: Display>(root: &Node<T>, indent: usize) -> String {
+    let mut output = "\t".repeat(indent) + &root.data.to_string() + "\n";
+
+    for child in root.children.borrow().iter() {
+        output += &format_tree(child, indent + 1);
+    }
+
+    output
+}
+
 #[test]
 pub fn weak_type() {
     // Weak is a version of Arc that holds a non-owning reference to the managed allocation.
@@ -180,74 +349,7 @@ pub fn weak_type() {
 
     example_prologue!("weak_type");
 
-    use std::cell::RefCell;
-    use std::rc::{Rc, Weak};
-    #[derive(Debug)]
-    struct Node {
-        name: String,                     // name of node
-        parent: RefCell<Vec<Weak<Node>>>, // An interiorly mutable vector of weakly referenced parent nodes
-        children: RefCell<Vec<Rc<Node>>>, // An interiorly mutable vector of strongly referenced children nodes
-    }
-
-    impl Node {
-        fn new(name: String) -> Rc<Node> {
-            // Factory method.
-            Rc::new(Node {
-                name,
-                parent: RefCell::new(vec![]),
-                children: RefCell::new(vec![]),
-            })
-        }
-
-        // Creates a new strong referenced child node,  adds a clone to itself to its parent,
-        // adds a weak referenced (downgraded version) of its parents to itself, and finally
-        // returns a downgraded version of itself.
-
-        fn add_child(parents: &Vec<Rc<Node>>, name: String) -> Weak<Node> {
-            let child = Node::new(name); //Create a new strong ref'd child node.
-
-            // Iterate its strong ref'd parent nodes.
-            parents.iter().for_each(|parent| {
-                //downgrade the parent to a weak ref and add it to itself.
-                child.parent.borrow_mut().push(Rc::downgrade(parent));
-                // Add a clone of itself to its strong ref'd parent.
-                parent.children.borrow_mut().push(child.clone())
-            });
-
-            return Rc::<Node>::downgrade(&child); // return a downgraded version of itself (weak ref)
-        }
-
-        fn print_tree(&self, recur_count: usize) {
-            println!(
-                " {} [{:?}] child of {:?}",
-                self.name,        // name of node
-                self as *const _, // address of node (debug formatter prints it in hex)
-                // Map the parent nodes into a collection of strings (parent names).
-                self.parent
-                    .borrow() // we borrow since its RefCell wrapped.
-                    .iter() // We get the iterator.
-                    // We attempt upgrade weakly ref'd parent nodes to strong ref'd ones, in
-                    // case the parent still lives in memory, we map its name otherwise we
-                    // map a defaulted "None". (perhaps the parent was destroyed but child node's
-                    // ownership still shared with other parents).
-                    .map(|p| p
-                        .upgrade() //Try upgrading to strong ref.
-                        .unwrap_or(Node::new(String::from("None"))) // grab its name if avail or default to "None"
-                        .name
-                        .clone()) //clone it because unwrap() gives temporary reference.
-                    .collect::<Vec<String>>() // collect it into a vector of strings that we can print.
-            );
-
-            //Iterate the children nodes recursively and tab out their print in multitude of recur_count.
-            for child in self.children.borrow().iter() {
-                for _ in 0..recur_count {
-                    print!("\t"); // create a tabbed indentation * recur_count
-                }
-                child.print_tree(recur_count + 1);
-            }
-        }
-    }
-
+    // Check out the module level Node struct declared above this example.
     // We aim to create one parent Branch node with 'CHILD_BRANCHES' child branches and
     // 'LEAFS' leaf nodes in which their ownership is shared between the two child branches.
 
@@ -295,50 +397,252 @@ pub fn weak_type() {
     //                 Leaf_2 [0x2121f3a92e0] child of ["Child Branch0", "Child Branch1"]
     //                 Leaf_3 [0x2121f3a9350] child of ["Child Branch0", "Child Branch1"]
     //                 Leaf_4 [0x2121f3a93c0] child of ["Child Branch0", "Child Branch1"]
+
+    // The leafs are shared by both child branches, which doesn't make for a cycle since the parents are only weakly referenced.
+    assert!(!parent_branches[0].has_cycle());
 }
+
 #[test]
-pub fn custom_smart_pointer() {
-    // We can create our own custom smart pointer type.
-    // while std lib smart pointer types like Box, String, Vec etc.. are usually backed by a heap allocator to store the data
-    // on the heap, this example however will wrap the data and always places it on the stack, however will demonstrate a typical
-    // smart pointer architecture via Deref, DerefMut and Drop traits implementations.
-    // PS: To see an example of a custom smart pointer backed by a heap allocation using libc, checkout unsafe_ops.rs.
+pub fn tree_cycle_detection() {
+    example_prologue!("tree_cycle_detection");
+
+    let root = Node::new("Root".to_string());
+    let child = Node::add_child(&vec![Rc::clone(&root)], "Child".to_string()).upgrade().unwrap();
+    assert!(!root.has_cycle());
+
+    // Deliberately make the root a (strongly referenced) child of its own child, creating a cycle.
+    child.children.borrow_mut().push(Rc::clone(&root));
+    assert!(root.has_cycle());
+    assert!(child.has_cycle());
+
+    // Break the cycle, otherwise both nodes would leak.
+    child.children.borrow_mut().clear();
+    assert!(!root.has_cycle());
+    assert_eq!(Rc::strong_count(&root), 1);
+}
 
-    example_prologue!("custom_smart_pointer");
+#[test]
+pub fn iterative_tree_traversal() {
+    example_prologue!("iterative_tree_traversal");
+
+    // A small tree first, to check the visiting order.
+    let root = Node::new("Root".to_string());
+    let branches = Node::add_child(&vec![Rc::clone(&root)], "Branch".to_string()).upgrade().unwrap();
+    Node::add_child(&vec![Rc::clone(&branches)], "Leaf_0".to_string());
+    Node::add_child(&vec![Rc::clone(&branches)], "Leaf_1".to_string());
+    Node::add_child(&vec![Rc::clone(&root)], "Sibling".to_string());
+
+    assert_eq!(
+        root.iter_depth_first(),
+        vec!["Root", "Branch", "Leaf_0", "Leaf_1", "Sibling"]
+    );
 
-    use std::ops::{Deref, DerefMut, Drop};
-    #[derive(Debug)]
-    struct MySmartPointer<T> {
-        data: T,
+    // Node data only needs to implement Display, so the traversal works just as well on numbers.
+    let root = Node::new(0);
+    Node::add_child(&vec![Rc::clone(&root)], 1);
+    assert_eq!(root.iter_depth_first(), vec!["0", "1"]);
+
+    // Now a deliberately deep chain of nodes, each node being the only child of the previous one.
+    const DEPTH: usize = 100_000;
+
+    let root = Node::new("Node_0".to_string());
+    let mut tail = Rc::clone(&root);
+    for i in 1..DEPTH {
+        tail = Node::add_child(&vec![tail], "Node_".to_string() + &i.to_string())
+            .upgrade()
+            .unwrap();
     }
 
-    impl<T> MySmartPointer<T> {
-        fn new(data: T) -> Self {
-            MySmartPointer { data }
-        }
+    let names = root.iter_depth_first();
+    assert_eq!(names.len(), DEPTH);
+    assert_eq!(names[DEPTH - 1], "Node_99999");
+    println!("Visited {} nodes, deepest node is {}", names.len(), names[DEPTH - 1]);
+
+    // Dropping a node drops its children first, which is recursive as well! so we unlink the chain
+    // from the top one node at a time to keep the drop glue from overflowing the stack.
+    drop(tail);
+    let mut next = root.children.take();
+    while let Some(node) = next.pop() {
+        next = node.children.take();
     }
-    impl<T> Deref for MySmartPointer<T> {
-        type Target = T;
-        fn deref(&self) -> &Self::Target {
-            // defines the behavior of dereferencing the MySmartPointer type. (e.g. *my_ptr)
-            &self.data
+}
+
+#[test]
+pub fn generic_print_tree() {
+    example_prologue!("generic_print_tree");
+
+    // A tree of integers, 1 has two children (2 and 3), and 3 has a single child (4).
+    let root = Node::new(1);
+    Node::add_child(&vec![Rc::clone(&root)], 2);
+    let three = Node::add_child(&vec![Rc::clone(&root)], 3).upgrade().unwrap();
+    Node::add_child(&vec![three], 4);
+
+    print_tree(&root, 0);
+
+    assert_eq!(format_tree(&root, 0), "1\n\t2\n\t3\n\t\t4\n");
+    assert_eq!(format_tree(&root, 1), "\t1\n\t\t2\n\t\t3\n\t\t\t4\n");
+
+    // Any Display type works, e.g. chars.
+    let root = Node::new('a');
+    Node::add_child(&vec![Rc::clone(&root)], 'b');
+    assert_eq!(format_tree(&root, 0), "a\n\tb\n");
+}
+
+// An n-ary tree without any shared ownership, unlike the Node above each tree owns its children directly in a Vec.
+// It can't express multiple parents nor walk back up to a parent, but in exchange it needs neither Rc, Weak nor RefCell,
+// adding a child is a plain mutation through &mut and dropping the root drops the whole tree.
+#[derive(Debug)]
+pub struct NTree<T> {
+    data: T,
+    children: Vec<NTree<T>>,
+}
+
+impl<T> NTree<T> {
+    pub fn new(data: T) -> Self {
+        NTree {
+            data,
+            children: vec![],
         }
     }
-    impl<T> DerefMut for MySmartPointer<T> {
-        fn deref_mut(&mut self) -> &mut Self::Target {
-            // defines the behavior of dereferencing the mut MySmartPointer type. (e.g. *my_mut_ptr)
-            &mut self.data
-        }
+
+    // Returns the newly added child so that children can be added to it in turn.
+    pub fn add_child(&mut self, data: T) -> &mut NTree<T> {
+        self.children.push(NTree::new(data));
+        self.children.last_mut().unwrap()
     }
-    impl<T> Drop for MySmartPointer<T> {
-        fn drop(&mut self) {
-            // defines what happens when the MySmartPointer object is dropped (after its lifetime expires,
-            // commonly after the containing scope exits). This is known as the RAII pattern in C++.
 
-            // for now we will leave this empty.
-            println!("Dropping MySmartPointer");
+    // Breadth first traversal grouping the nodes data by depth, the root alone being the first level.
+    // The queue holds the nodes left to visit along with their depth, since the nodes of a level are all queued
+    // before the nodes of the next one, a depth greater than the last group's starts a new group.
+    pub fn level_order(&self) -> Vec<Vec<&T>> {
+        let mut levels: Vec<Vec<&T>> = vec![];
+        let mut queue = std::collections::VecDeque::from([(self, 0)]);
+
+        while let Some((node, depth)) = queue.pop_front() {
+            if depth == levels.len() {
+                levels.push(vec![]);
+            }
+            levels[depth].push(&node.data);
+            queue.extend(node.children.iter().map(|child| (child, depth + 1)));
         }
+
+        levels
     }
+}
+
+#[test]
+pub fn ntree_level_order() {
+    example_prologue!("ntree_level_order");
+
+    // Root
+    // ├── Branch_0
+    // │   ├── Leaf_0
+    // │   └── Leaf_1
+    // └── Branch_1
+    //     └── Leaf_2
+    //         └── Twig
+    let mut root = NTree::new("Root");
+    let branch = root.add_child("Branch_0");
+    branch.add_child("Leaf_0");
+    branch.add_child("Leaf_1");
+    root.add_child("Branch_1").add_child("Leaf_2").add_child("Twig");
+
+    let levels = root.level_order();
+    println!("Levels : {:?}", levels);
+
+    assert_eq!(
+        levels,
+        vec![
+            vec![&"Root"],
+            vec![&"Branch_0", &"Branch_1"],
+            vec![&"Leaf_0", &"Leaf_1", &"Leaf_2"],
+            vec![&"Twig"],
+        ]
+    );
+    assert_eq!(NTree::new(1).level_order(), vec![vec![&1]]);
+}
+
+// The MySmartPointer used by the custom_smart_pointer example, declared at the module level so it can be used by other examples as well.
+// PartialEq, Eq and Hash are derived so it can be used as a HashMap key (see custom_smart_pointer_borrow example).
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct MySmartPointer<T> {
+    data: T,
+}
+
+impl<T> MySmartPointer<T> {
+    pub fn new(data: T) -> Self {
+        MySmartPointer { data }
+    }
+}
+impl<T> Deref for MySmartPointer<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        // defines the behavior of dereferencing the MySmartPointer type. (e.g. *my_ptr)
+        &self.data
+    }
+}
+impl<T> DerefMut for MySmartPointer<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // defines the behavior of dereferencing the mut MySmartPointer type. (e.g. *my_mut_ptr)
+        &mut self.data
+    }
+}
+impl<T> AsRef<T> for MySmartPointer<T> {
+    // Allows passing a &MySmartPointer<T> to APIs expecting an `impl AsRef<T>`, like Deref but explicit.
+    fn as_ref(&self) -> &T {
+        &self.data
+    }
+}
+impl<T> std::borrow::Borrow<T> for MySmartPointer<T> {
+    // Borrow is stricter than AsRef, it promises that Eq, Ord and Hash behave identically on the borrowed form,
+    // which is what allows collections like HashMap to lookup an owned key by its borrowed form.
+    fn borrow(&self) -> &T {
+        &self.data
+    }
+}
+impl std::borrow::Borrow<str> for MySmartPointer<String> {
+    // String hashes the same as str, so we can go one step further and allow lookups by &str
+    // the same way a HashMap<String, V> allows it.
+    fn borrow(&self) -> &str {
+        &self.data
+    }
+}
+impl<T: IntoIterator> IntoIterator for MySmartPointer<T> {
+    // Forwards the iteration to the wrapped collection, consuming the pointer.
+    type Item = T::Item;
+    type IntoIter = T::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // We can't simply move `self.data` out since MySmartPointer implements Drop (error E0509),
+        // the compiler wouldn't be able to run our drop on a partially moved value.
+        // Instead we wrap self in a ManuallyDrop so our Drop impl won't run, then bitwise copy the data out of it,
+        // this is sound since the original `self` is never used nor dropped afterwards.
+        let this = std::mem::ManuallyDrop::new(self);
+        let data = unsafe { std::ptr::read(&this.data) };
+        data.into_iter()
+    }
+}
+impl<T> Drop for MySmartPointer<T> {
+    fn drop(&mut self) {
+        // defines what happens when the MySmartPointer object is dropped (after its lifetime expires,
+        // commonly after the containing scope exits). This is known as the RAII pattern in C++.
+
+        // for now we will leave this empty.
+        println!("Dropping MySmartPointer");
+    }
+}
+
+#[test]
+pub fn custom_smart_pointer() {
+    // We can create our own custom smart pointer type.
+    // while std lib smart pointer types like Box, String, Vec etc.. are usually backed by a heap allocator to store the data
+    // on the heap, this example however will wrap the data and always places it on the stack, however will demonstrate a typical
+    // smart pointer architecture via Deref, DerefMut and Drop traits implementations.
+    // PS: To see an example of a custom smart pointer backed by a heap allocation using libc, checkout unsafe_ops.rs.
+
+    example_prologue!("custom_smart_pointer");
+
+    // Check out the module level MySmartPointer declared above this example.
 
     // Although our smart pointer type places any generic object being passed to it on the stack,
     // if it happens to wrap a heap-allocator backed type object, like String like used below
@@ -369,3 +673,321 @@ pub fn custom_smart_pointer() {
         "This is a proof that the pointers were dropped before the exit of this function scope"
     );
 }
+
+#[test]
+pub fn custom_smart_pointer_borrow() {
+    example_prologue!("custom_smart_pointer_borrow");
+
+    use std::collections::HashMap;
+
+    // AsRef lets us pass the pointer to functions that are generic over anything that can be viewed as a &String.
+    fn shout(s: impl AsRef<String>) -> String {
+        s.as_ref().to_uppercase()
+    }
+    assert_eq!(shout(MySmartPointer::new(String::from("hi"))), "HI");
+
+    // Borrow is what makes HashMap::get accept a borrowed form of the key, here we store
+    // MySmartPointer<String> keys and lookup by plain &str without constructing a MySmartPointer.
+    let mut scores: HashMap<MySmartPointer<String>, u32> = HashMap::new();
+    scores.insert(MySmartPointer::new(String::from("alice")), 10);
+    scores.insert(MySmartPointer::new(String::from("bob")), 20);
+
+    assert_eq!(scores.get("alice"), Some(&10));
+    assert_eq!(scores.get("bob"), Some(&20));
+    assert_eq!(scores.get("carol"), None);
+
+    // Lookups by &String work through the generic Borrow<T> impl.
+    assert_eq!(scores.get(&String::from("bob")), Some(&20));
+}
+
+#[test]
+pub fn custom_smart_pointer_into_iter() {
+    example_prologue!("custom_smart_pointer_into_iter");
+
+    let my_ptr = MySmartPointer::new(vec![1, 2, 3, 4]);
+
+    // Thanks to the IntoIterator forwarding, the pointer can be iterated directly in a for loop
+    // just like the Vec it wraps, consuming both.
+    let mut sum = 0;
+    for value in my_ptr {
+        println!("value = {}", value);
+        sum += value;
+    }
+    assert_eq!(sum, 10);
+}
+
+// Cow (Clone on Write) is a smart pointer that either borrows its data (Cow::Borrowed) or owns it (Cow::Owned),
+// and derefs to the borrowed form either way. It shines when a function only needs to allocate some of the time,
+// the common path returns a borrow and costs nothing while the rare path returns an owned value.
+
+// Merges comma separated config options, e.g. "debug,verbose" with an override of "color" gives "debug,verbose,color".
+// Nothing needs to be allocated unless both sides have options, in which case a new combined String is built.
+pub fn merge_config<'a>(base: &'a str, override_val: Option<&'a str>) -> std::borrow::Cow<'a, str> {
+    use std::borrow::Cow;
+
+    match override_val {
+        None | Some("") => Cow::Borrowed(base),
+        Some(override_val) if base.is_empty() => Cow::Borrowed(override_val),
+        Some(override_val) => Cow::Owned(format!("{},{}", base, override_val)),
+    }
+}
+
+#[test]
+pub fn cow_merge_config_borrowed() {
+    example_prologue!("cow_merge_config_borrowed");
+
+    use std::borrow::Cow;
+
+    let base = "debug,verbose";
+    let merged = merge_config(base, None);
+    println!("Merged config : {}", merged);
+
+    assert!(matches!(merged, Cow::Borrowed(_)));
+    assert_eq!(merged, "debug,verbose");
+    // Borrowed means no copy, it's the very same memory as the base.
+    assert_eq!(merged.as_ptr(), base.as_ptr());
+
+    assert!(matches!(merge_config(base, Some("")), Cow::Borrowed("debug,verbose")));
+    assert!(matches!(merge_config("", Some("color")), Cow::Borrowed("color")));
+}
+
+#[test]
+pub fn cow_merge_config_owned() {
+    example_prologue!("cow_merge_config_owned");
+
+    use std::borrow::Cow;
+
+    let merged = merge_config("debug,verbose", Some("color"));
+    println!("Merged config : {}", merged);
+
+    assert!(matches!(merged, Cow::Owned(_)));
+    assert_eq!(merged, "debug,verbose,color");
+
+    // into_owned gives back the String, without a copy since it's already owned.
+    let owned: String = merged.into_owned();
+    assert_eq!(owned, "debug,verbose,color");
+}
+
+// The Observer pattern: a Subject notifies a list of registered observers whenever an event occurs.
+// If the subject held strong references (Rc) to its observers, it would keep them alive for as long as
+// the subject lives even after everyone else is done with them (a leak in practice). Holding Weak references
+// instead lets the observers be dropped by their owners, the subject simply skips the ones that can't be upgraded.
+
+pub trait Observer {
+    fn on_event(&mut self, event: &str);
+}
+
+#[derive(Default)]
+pub struct Subject {
+    // RefCell is required so that observers can be mutated through a shared (Rc/Weak) reference.
+    observers: Vec<Weak<RefCell<dyn Observer>>>,
+}
+
+impl Subject {
+    pub fn register(&mut self, observer: &Rc<RefCell<dyn Observer>>) {
+        self.observers.push(Rc::downgrade(observer));
+    }
+
+    pub fn notify(&self, event: &str) {
+        for observer in &self.observers {
+            // upgrade returns None if the observer has already been dropped.
+            if let Some(observer) = observer.upgrade() {
+                observer.borrow_mut().on_event(event);
+            }
+        }
+    }
+}
+
+#[test]
+pub fn observer_pattern() {
+    example_prologue!("observer_pattern");
+
+    // Both observers record the events they receive into a shared log buffer.
+    struct Recorder {
+        name: String,
+        log: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Observer for Recorder {
+        fn on_event(&mut self, event: &str) {
+            println!("{} received : {}", self.name, event);
+            self.log.borrow_mut().push(format!("{}:{}", self.name, event));
+        }
+    }
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    // Rc<RefCell<Recorder>> coerces into Rc<RefCell<dyn Observer>> (unsized coercion).
+    let observer_1: Rc<RefCell<dyn Observer>> = Rc::new(RefCell::new(Recorder {
+        name: "Observer1".to_owned(),
+        log: Rc::clone(&log),
+    }));
+    let observer_2: Rc<RefCell<dyn Observer>> = Rc::new(RefCell::new(Recorder {
+        name: "Observer2".to_owned(),
+        log: Rc::clone(&log),
+    }));
+
+    let mut subject = Subject::default();
+    subject.register(&observer_1);
+    subject.register(&observer_2);
+
+    subject.notify("Spawned");
+    assert_eq!(*log.borrow(), vec!["Observer1:Spawned", "Observer2:Spawned"]);
+
+    // Dropping the only strong reference frees the observer even though the subject still holds a Weak to it.
+    drop(observer_1);
+    log.borrow_mut().clear();
+
+    subject.notify("Moved");
+    assert_eq!(*log.borrow(), vec!["Observer2:Moved"]);
+    assert_eq!(Rc::strong_count(&observer_2), 1); // the subject never took ownership.
+}
+
+// A doubly linked list tying Rc, RefCell and Weak together.
+// Every node is shared by two neighbors, so it is wrapped by an Rc, and since its links need to be updated
+// through these shared references it is also wrapped by a RefCell (interior mutability).
+// If both 'next' and 'prev' links were strong (Rc), every pair of neighbors would own each other, a reference
+// cycle that would never get dropped (a memory leak), so the backward links are Weak references.
+
+type Link<T> = Option<Rc<RefCell<ListNode<T>>>>;
+
+struct ListNode<T> {
+    value: T,
+    next: Link<T>,                            // strong link, a node owns the node after it.
+    prev: Option<Weak<RefCell<ListNode<T>>>>, // weak link, a node doesn't own the node before it.
+}
+
+pub struct DoublyLinkedList<T> {
+    head: Link<T>,                            // the list owns the first node, which owns the next one and so on.
+    tail: Option<Weak<RefCell<ListNode<T>>>>, // the last node is already owned by the node before it.
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> Self {
+        DoublyLinkedList {
+            head: None,
+            tail: None,
+        }
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let node = Rc::new(RefCell::new(ListNode {
+            value,
+            next: None,
+            prev: None,
+        }));
+
+        match self.tail.as_ref().and_then(|tail| tail.upgrade()) {
+            Some(old_tail) => {
+                node.borrow_mut().prev = Some(Rc::downgrade(&old_tail));
+                old_tail.borrow_mut().next = Some(Rc::clone(&node));
+            }
+            None => self.head = Some(Rc::clone(&node)), // empty list, the node is the head as well.
+        }
+
+        self.tail = Some(Rc::downgrade(&node));
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let node = Rc::new(RefCell::new(ListNode {
+            value,
+            next: None,
+            prev: None,
+        }));
+
+        match self.head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(Rc::downgrade(&node));
+                node.borrow_mut().next = Some(old_head);
+            }
+            None => self.tail = Some(Rc::downgrade(&node)), // empty list, the node is the tail as well.
+        }
+
+        self.head = Some(node);
+    }
+
+    // Iterates the list from front to back. The values are cloned since they live behind a RefCell,
+    // handing out plain references to them would outlive the RefCell borrow.
+    pub fn iter(&self) -> ListIter<T> {
+        ListIter {
+            next: self.head.clone(),
+        }
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The default drop would drop the head, which drops the next node and so on recursively, so a long enough
+// list would overflow the stack. Unlinking the nodes one at a time avoids the recursion.
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = node.borrow_mut().next.take();
+        }
+    }
+}
+
+pub struct ListIter<T> {
+    next: Link<T>,
+}
+
+impl<T: Clone> Iterator for ListIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next.take()?;
+        let node = node.borrow();
+        self.next = node.next.clone();
+        Some(node.value.clone())
+    }
+}
+
+#[test]
+pub fn doubly_linked_list() {
+    example_prologue!("doubly_linked_list");
+
+    let mut list = DoublyLinkedList::new();
+    list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    list.push_back(4);
+
+    println!("List = {:?}", list.iter().collect::<Vec<_>>());
+    assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+    // The backward links lead to the same nodes in reverse.
+    let tail = list.tail.as_ref().unwrap().upgrade().unwrap();
+    let before_tail = tail.borrow().prev.as_ref().unwrap().upgrade().unwrap();
+    assert_eq!(tail.borrow().value, 4);
+    assert_eq!(before_tail.borrow().value, 3);
+}
+
+#[test]
+pub fn doubly_linked_list_no_leak() {
+    let mut list = DoublyLinkedList::new();
+    for i in 0..5 {
+        list.push_back(i.to_string());
+    }
+
+    // Keep a weak reference to every node to be able to check on them after the list is gone.
+    let mut nodes = vec![];
+    let mut next = list.head.clone();
+    while let Some(node) = next {
+        nodes.push(Rc::downgrade(&node));
+        next = node.borrow().next.clone();
+    }
+    assert_eq!(nodes.len(), 5);
+
+    // Every node is owned exactly once, by the list head or the node before it.
+    assert!(nodes.iter().all(|node| node.strong_count() == 1));
+
+    drop(list);
+
+    // Had the prev links been strong references, the nodes would have kept each other alive.
+    assert!(nodes.iter().all(|node| node.strong_count() == 0));
+}
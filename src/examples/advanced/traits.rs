@@ -35,19 +35,61 @@ enum Texture {
     Cloth(ClothTexture),
 }
 
-#[derive(Debug, Clone, Copy)]
+// Enum variants holding data can't be marked as the #[default] variant, so Default is implemented manually.
+// A plain entity defaults to wood, the Player and NPC defaults below pick their own cloth textures.
+impl Default for Texture {
+    fn default() -> Self {
+        Texture::Wood(WoodTexture::Oak)
+    }
+}
+
+// Deriving Default defaults every field, 0.0 for the coordinates, i.e. the origin.
+#[derive(Debug, Clone, Copy, Default)]
 struct Vector2 {
     x: f32,
     y: f32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct Entity {
     location: Vector2,
     name: String,
     texture: Texture,
 }
 
+// While Debug can be derived, Display is meant for user facing output and has to be implemented manually.
+// Implementing it also gives us the to_string() method for free (via a blanket impl in the std lib).
+impl std::fmt::Display for Entity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Nested fields can be formatted via their own formatting traits, Debug ({:?}) for the texture enum.
+        write!(
+            f,
+            "{} at ({}, {}) [{:?}]",
+            self.name, self.location.x, self.location.y, self.texture
+        )
+    }
+}
+
+// Consuming setters take self by value and hand it back, so they chain right off Entity::default() without a separate
+// builder type. Compared to the UserBuilder (check out structs.rs), there are no Option fields nor a fallible build step
+// since every field already holds its default, the tradeoff being that a forgotten field goes unnoticed.
+impl Entity {
+    fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    fn with_location(mut self, location: Vector2) -> Self {
+        self.location = location;
+        self
+    }
+
+    fn with_texture(mut self, texture: Texture) -> Self {
+        self.texture = texture;
+        self
+    }
+}
+
 #[derive(Debug)]
 struct Player {
     entity: Entity,
@@ -58,15 +100,67 @@ struct NPC {
     entity: Entity,
 }
 
+// A defaulted Player or NPC is an unnamed entity at the origin wearing the cloth texture of its type.
+// The struct update syntax (..) fills in the remaining fields from the defaulted Entity.
+impl Default for Player {
+    fn default() -> Self {
+        Player {
+            entity: Entity {
+                texture: Texture::Cloth(ClothTexture::Tactical),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Default for NPC {
+    fn default() -> Self {
+        NPC {
+            entity: Entity {
+                texture: Texture::Cloth(ClothTexture::Ninja),
+                ..Default::default()
+            },
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Door {
+    id: u64, // unique id handed out by next_id.
     entity: Entity,
 }
 
 #[derive(Debug)]
 struct Chest {
+    id: u64,
     entity: Entity,
 }
+
+// Hands out unique ids, safe to be used from any number of threads at once.
+// fetch_add atomically increments the counter and returns its previous value, so two threads can never be handed
+// the same value. Relaxed ordering is enough since we only need the counter itself to be consistent, no other
+// memory is synchronized through it.
+struct IdGenerator(std::sync::atomic::AtomicU64);
+
+impl IdGenerator {
+    // const so it can initialize a static.
+    const fn new() -> Self {
+        IdGenerator(std::sync::atomic::AtomicU64::new(1))
+    }
+
+    fn next(&self) -> u64 {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+// A single generator shared by the whole program, statics holding atomics don't need to be 'static mut' (nor unsafe)
+// since atomics are mutated through shared references.
+static ENTITY_IDS: IdGenerator = IdGenerator::new();
+
+// Returns a new unique, monotonically increasing id.
+fn next_id() -> u64 {
+    ENTITY_IDS.next()
+}
 //Interaction trait to be implemented by both Door & Chest.
 trait Interaction: std::fmt::Debug { // traits can extend a super trait or multiple using the '+' operator.
 
@@ -80,6 +174,13 @@ trait Interaction: std::fmt::Debug { // traits can extend a super trait or multi
 
     //prototype function
     fn lock(&self);
+
+    fn id(&self) -> u64;
+
+    // Upcasts to the Any trait object, which knows the concrete type behind it (check the downcasting section below).
+    // It can't be given a default impl here since converting self to &dyn Any requires Self to be Sized,
+    // which would make the method uncallable on a dyn Interaction, hence every type implements it.
+    fn as_any(&self) -> &dyn std::any::Any;
 }
 
 impl Interaction for Door {
@@ -91,6 +192,14 @@ impl Interaction for Door {
     fn lock(&self) {
         println!("Called the Door::lock function");
     }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
 }
 
 impl Interaction for Chest {
@@ -99,6 +208,14 @@ impl Interaction for Chest {
     fn lock(&self) {
         println!("Called the Chest::lock function");
     }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
 }
 
 //Mobility trait to be implemented by both Player & NPC.
@@ -127,6 +244,115 @@ impl Mobility for NPC {
     }
 }
 
+// The From trait allows a type to define how to create itself from another type, and implementing it
+// gives us the reciprocal Into trait for free via a blanket implementation in the std lib.
+// Converting a Player into an NPC (and vice versa) moves the inner Entity rather than cloning it,
+// only swapping the cloth texture to that of the target type.
+
+impl From<Player> for NPC {
+    fn from(player: Player) -> Self {
+        NPC {
+            entity: Entity {
+                texture: Texture::Cloth(ClothTexture::Ninja),
+                ..player.entity // struct update syntax moves the remaining fields out of the player's entity.
+            },
+        }
+    }
+}
+
+impl From<NPC> for Player {
+    fn from(npc: NPC) -> Self {
+        Player {
+            entity: Entity {
+                texture: Texture::Cloth(ClothTexture::Tactical),
+                ..npc.entity
+            },
+        }
+    }
+}
+
+// Conversions aren't limited to our own types, a Vector2 converts to and from a plain (x, y) tuple.
+// The tuple is a foreign type, but implementing From for it is allowed since Vector2 (a local type) appears in the impl.
+impl From<(f32, f32)> for Vector2 {
+    fn from((x, y): (f32, f32)) -> Self {
+        Vector2 { x, y }
+    }
+}
+
+impl From<Vector2> for (f32, f32) {
+    fn from(v: Vector2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+// The kinds of interactables that can be randomly spawned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InteractableKind {
+    Door,
+    Chest,
+}
+
+// Randomly picks one of the items where each item's odds of being picked are proportional to its weight.
+// e.g. for [(a, 1), (b, 3)], 'a' is picked 1 in 4 times and 'b' 3 in 4 times.
+// Panics if the total weight is 0 since there would be nothing to pick from.
+fn weighted_choice<'a, T>(rng: &mut impl rand::Rng, items: &'a [(T, u32)]) -> &'a T {
+    let total: u32 = items.iter().map(|(_, weight)| weight).sum();
+
+    // Roll a number in the [0, total[ range and find the item whose weight range the roll lands in.
+    let mut roll = rng.gen_range(0..total);
+    for (item, weight) in items {
+        if roll < *weight {
+            return item;
+        }
+        roll -= weight;
+    }
+
+    unreachable!("The roll is always less than the total weight")
+}
+
+// The odds of spawning each kind of interactable are configurable via the 'spawn_weights' (check weighted_choice).
+fn spawn_random_interactable(
+    name: String,
+    spawn_weights: &[(InteractableKind, u32)],
+) -> Box<dyn Interaction> {
+    use rand::Rng; //using Rng from rand crate (https://docs.rs/rand/0.8.5/rand/trait.Rng.html)
+    let mut rng = rand::thread_rng(); // random generator
+    let rand_n = rng.gen_range(0..=10);
+
+    let location = Vector2 {
+        // randomly generate a location
+        x: rng.gen_range(0.0..100.0),
+        y: rng.gen_range(0.0..100.0),
+    };
+
+    let texture = if rand_n % 2 == 0 {
+        //randomly generate a texture
+        Texture::Wood(WoodTexture::Oak)
+    } else {
+        Texture::Wood(WoodTexture::Willow)
+    };
+
+    //Spawn a random interactable on the heap.
+    match weighted_choice(&mut rng, spawn_weights) {
+        InteractableKind::Door => Box::new(Door {
+            id: next_id(),
+            entity: Entity {
+                location,
+                name,
+                texture,
+            },
+        }),
+        InteractableKind::Chest => Box::new(Chest {
+            id: next_id(),
+            entity: Entity {
+                location,
+                name,
+                texture,
+            },
+        }),
+    }
+}
+
 #[test]
 pub fn main() {
     example_prologue!("Traits");
@@ -151,53 +377,27 @@ pub fn main() {
         },
     };
 
+    // Print the entities via their Display implementation.
+    println!("Spawned {}", player.entity);
+    println!("Spawned {}", npc.entity);
+
     // We can return an object of a boxed dynamic Interaction type in here for the same reason
     // explained in the NPC implementation of the Mobility trait, therefore the interactables
     // are going to be placed on the heap.
 
-    fn spawn_random_interactable(name: String) -> Box<dyn Interaction> {
-        use rand::Rng; //using Rng from rand crate (https://docs.rs/rand/0.8.5/rand/trait.Rng.html)
-        let mut rng = rand::thread_rng(); // random generator
-        let rand_n = rng.gen_range(0..=10);
-
-        let location = Vector2 {
-            // randomly generate a location
-            x: rng.gen_range(0.0..100.0),
-            y: rng.gen_range(0.0..100.0),
-        };
-
-        let texture = if rand_n % 2 == 0 {
-            //randomly generate a texture
-            Texture::Wood(WoodTexture::Oak)
-        } else {
-            Texture::Wood(WoodTexture::Willow)
-        };
-
-        //Spawn a random interactable on the heap.
-        match rand_n {
-            0..=4 => Box::new(Door {
-                entity: Entity {
-                    location,
-                    name,
-                    texture,
-                },
-            }),
-            _ => Box::new(Chest {
-                entity: Entity {
-                    location,
-                    name,
-                    texture,
-                },
-            }),
-        }
-    }
+    // Check out the module level spawn_random_interactable function declared above this example.
 
     let mut interactables = Vec::new(); // vector of interactable objects.
 
+    // Doors spawn with 5 in 11 odds, and chests with 6 in 11 odds.
+    let spawn_weights = [(InteractableKind::Door, 5), (InteractableKind::Chest, 6)];
+
     // Spawn 5 interactables of random types (door or chest).
     for i in 0..5 {
-        let interactable =
-            spawn_random_interactable("Interactable_".to_owned() + i.to_string().as_str());
+        let interactable = spawn_random_interactable(
+            "Interactable_".to_owned() + i.to_string().as_str(),
+            &spawn_weights,
+        );
         interactables.push(interactable);
     }
 
@@ -245,3 +445,548 @@ pub fn main() {
 
     print_mobile_classic(&player);
 }
+
+#[test]
+pub fn from_into_conversion() {
+    example_prologue!("from_into_conversion");
+
+    let mut player = Player::default();
+    player.entity.location = Vector2 { x: 5.0, y: 10.0 };
+    player.entity.name = "Player1".to_owned();
+
+    // 'player' is moved into the conversion and can't be used afterwards.
+    let npc = NPC::from(player);
+    println!("Converted player into {:?}", npc);
+
+    assert_eq!(npc.entity.name, "Player1");
+    assert_eq!((npc.entity.location.x, npc.entity.location.y), (5.0, 10.0));
+    assert!(matches!(
+        npc.entity.texture,
+        Texture::Cloth(ClothTexture::Ninja)
+    ));
+
+    // The Into counterpart requires the target type to be annotated since it can't be inferred.
+    let player: Player = npc.into();
+    println!("Converted npc back into {:?}", player);
+
+    assert_eq!(player.entity.name, "Player1");
+    assert!(matches!(
+        player.entity.texture,
+        Texture::Cloth(ClothTexture::Tactical)
+    ));
+}
+
+#[test]
+pub fn vector2_tuple_conversion() {
+    example_prologue!("vector2_tuple_conversion");
+
+    let v = Vector2::from((1.0, 2.0));
+    println!("Vector2 from a tuple : {:?}", v);
+    assert_eq!((v.x, v.y), (1.0, 2.0));
+
+    // Round trip, Vector2 being Copy it is still usable after the conversion.
+    let tuple: (f32, f32) = v.into();
+    assert_eq!(tuple, (1.0, 2.0));
+    let back: Vector2 = tuple.into();
+    assert_eq!((back.x, back.y), (v.x, v.y));
+
+    // Any function taking an impl Into<Vector2> accepts a tuple literal as well.
+    fn teleport(entity: &mut Entity, dest: impl Into<Vector2>) {
+        entity.location = dest.into();
+    }
+    let mut player = Player::default();
+    teleport(&mut player.entity, (-4.5, 8.0));
+    assert_eq!(<(f32, f32)>::from(player.entity.location), (-4.5, 8.0));
+}
+
+////// Blanket implementations //////
+
+// We can implement a trait for every type that satisfies a set of trait bounds at once, this is known
+// as a blanket implementation. The std lib makes use of it heavily, e.g. 'impl<T: Display> ToString for T'
+// is the reason why we can call 'to_string()' on any type implementing the Display trait.
+
+// Any type implementing the Debug trait (derived or manually implemented) gets to be printable.
+pub trait Printable {
+    fn print_me(&self);
+}
+
+impl<T: std::fmt::Debug> Printable for T {
+    fn print_me(&self) {
+        println!("Printable => {:?}", self);
+    }
+}
+
+#[test]
+pub fn blanket_impl() {
+    example_prologue!("blanket_impl");
+
+    use crate::examples::modules::instruments::string::guitar::Guitar;
+    use crate::examples::prototype_structures::enums::Message;
+    use crate::examples::prototype_structures::structs::Rectangle;
+
+    // None of the following types implement Printable explicitly, they all get it via the blanket impl.
+    Guitar::default().print_me();
+    Rectangle {
+        width: 800,
+        height: 600,
+    }
+    .print_me();
+    Message::Write(String::from("Hello World")).print_me();
+    Vector2 { x: 1.0, y: 2.0 }.print_me();
+    Texture::Wood(WoodTexture::Yew).print_me();
+
+    // Trait bounds can then be expressed in terms of Printable rather than Debug.
+    fn print_all(printables: &[&dyn Printable]) {
+        for p in printables {
+            p.print_me();
+        }
+    }
+
+    print_all(&[&Guitar::default(), &Message::Quit, &42, &"str slices are Debug too"]);
+}
+
+////// Associated constants //////
+
+// Traits can also declare associated constants, which like functions can either be left for the implementing
+// type to define or be given a default value that can be overridden.
+// **Note that a trait declaring an associated constant is no longer usable as a trait object (dyn Trait) since
+// the constant is resolved per concrete type at compile time, that's why we don't add it to the Interaction
+// trait (used as Box<dyn Interaction>) and declare a separate Durability trait instead.
+
+trait Durability {
+    const DEFAULT_DURABILITY: u32 = 100; // defaulted constant.
+
+    // Number of hits it takes to break the object.
+    fn durability(&self) -> u32 {
+        Self::DEFAULT_DURABILITY // Self refers to the implementing type, so the overridden constant is used.
+    }
+}
+
+impl Durability for Door {
+    const DEFAULT_DURABILITY: u32 = 250; // doors are sturdy.
+}
+
+impl Durability for Chest {
+    const DEFAULT_DURABILITY: u32 = 50; // chests not so much.
+}
+
+#[test]
+pub fn associated_constants() {
+    example_prologue!("associated_constants");
+
+    let door = Door {
+        id: next_id(),
+        entity: Entity {
+            location: Vector2 { x: 0.0, y: 0.0 },
+            name: "Door1".to_owned(),
+            texture: Texture::Wood(WoodTexture::Oak),
+        },
+    };
+
+    let chest = Chest {
+        id: next_id(),
+        entity: Entity {
+            location: Vector2 { x: 0.0, y: 0.0 },
+            name: "Chest1".to_owned(),
+            texture: Texture::Wood(WoodTexture::Willow),
+        },
+    };
+
+    println!("{} durability = {}", door.entity.name, door.durability());
+    println!("{} durability = {}", chest.entity.name, chest.durability());
+
+    // Associated constants can also be accessed through the type without an instance.
+    assert_eq!(door.durability(), Door::DEFAULT_DURABILITY);
+    assert_eq!(chest.durability(), <Chest as Durability>::DEFAULT_DURABILITY);
+    assert_ne!(door.durability(), chest.durability());
+}
+
+#[test]
+pub fn weighted_random_selection() {
+    example_prologue!("weighted_random_selection");
+
+    use rand::SeedableRng;
+
+    // A seeded random generator always generates the same sequence of numbers, making the test reproducible.
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+    let items = [("Common", 70), ("Rare", 25), ("Legendary", 5), ("Never", 0)];
+
+    const SAMPLES: usize = 100_000;
+    let mut counts = std::collections::HashMap::new();
+    for _ in 0..SAMPLES {
+        *counts.entry(*weighted_choice(&mut rng, &items)).or_insert(0) += 1;
+    }
+    println!("Picked counts over {} samples : {:?}", SAMPLES, counts);
+
+    // Every item's share of the picks should roughly match its share of the total weight (100).
+    for (item, weight) in items {
+        let share = *counts.get(item).unwrap_or(&0) as f64 / SAMPLES as f64;
+        assert!(
+            (share - weight as f64 / 100.0).abs() < 0.01,
+            "{} was picked {} of the time",
+            item,
+            share
+        );
+    }
+    assert!(!counts.contains_key("Never")); // a weight of 0 is never picked.
+
+    assert_eq!(
+        *weighted_choice(&mut rng, &[(InteractableKind::Chest, 1)]),
+        InteractableKind::Chest
+    );
+}
+
+#[test]
+pub fn entity_display() {
+    example_prologue!("entity_display");
+
+    let entity = Entity {
+        location: Vector2 { x: 1.5, y: -3.0 },
+        name: "Door1".to_owned(),
+        texture: Texture::Wood(WoodTexture::Oak),
+    };
+
+    println!("{}", entity);
+    assert_eq!(entity.to_string(), "Door1 at (1.5, -3) [Wood(Oak)]");
+}
+
+#[test]
+pub fn consuming_setters() {
+    example_prologue!("consuming_setters");
+
+    let entity = Entity::default()
+        .with_name("Chest1")
+        .with_location(Vector2 { x: 4.0, y: 2.5 })
+        .with_texture(Texture::Wood(WoodTexture::Yew))
+        .with_name("Chest2"); // a later call overrides an earlier one.
+
+    println!("Built entity : {}", entity);
+    assert_eq!(entity.name, "Chest2");
+    assert_eq!((entity.location.x, entity.location.y), (4.0, 2.5));
+    assert!(matches!(entity.texture, Texture::Wood(WoodTexture::Yew)));
+
+    // The fields that weren't set keep their defaults.
+    let entity = Entity::default().with_name("Door1");
+    assert_eq!((entity.location.x, entity.location.y), (0.0, 0.0));
+    assert!(matches!(entity.texture, Texture::Wood(WoodTexture::Oak)));
+}
+
+#[test]
+pub fn default_entities() {
+    example_prologue!("default_entities");
+
+    let player = Player::default();
+    println!("Default player : {}", player.entity);
+
+    assert_eq!((player.entity.location.x, player.entity.location.y), (0.0, 0.0));
+    assert!(player.entity.name.is_empty());
+    assert!(matches!(
+        player.entity.texture,
+        Texture::Cloth(ClothTexture::Tactical)
+    ));
+
+    assert!(matches!(
+        NPC::default().entity.texture,
+        Texture::Cloth(ClothTexture::Ninja)
+    ));
+    assert!(matches!(
+        Entity::default().texture,
+        Texture::Wood(WoodTexture::Oak)
+    ));
+}
+
+////// Downcasting trait objects //////
+
+// Once boxed as a Box<dyn Interaction> the concrete type (Door or Chest) is erased, only the trait methods remain callable.
+// The Any trait is implemented for every 'static type and is able to check at runtime whether it holds a given concrete type,
+// so upcasting to &dyn Any (via as_any) then downcasting with downcast_ref recovers the concrete type, or None if it's another one.
+// Methods can be implemented on the trait object type itself (dyn Interaction) just like on any other type.
+impl dyn Interaction {
+    fn as_door(&self) -> Option<&Door> {
+        self.as_any().downcast_ref::<Door>()
+    }
+
+    fn as_chest(&self) -> Option<&Chest> {
+        self.as_any().downcast_ref::<Chest>()
+    }
+}
+
+#[test]
+pub fn downcasting_trait_objects() {
+    example_prologue!("downcasting_trait_objects");
+
+    // A weight of 0 never gets picked, which lets us spawn a specific kind.
+    let door_weights = [(InteractableKind::Door, 1), (InteractableKind::Chest, 0)];
+    let chest_weights = [(InteractableKind::Door, 0), (InteractableKind::Chest, 1)];
+
+    let interactables = [
+        spawn_random_interactable("Door1".to_owned(), &door_weights),
+        spawn_random_interactable("Chest1".to_owned(), &chest_weights),
+        spawn_random_interactable("Door2".to_owned(), &door_weights),
+    ];
+
+    let doors = interactables
+        .iter()
+        .filter_map(|interactable| interactable.as_door())
+        .map(|door| door.entity.name.as_str())
+        .collect::<Vec<_>>();
+    println!("Recovered doors : {:?}", doors);
+    assert_eq!(doors, vec!["Door1", "Door2"]);
+
+    let chest = interactables[1].as_chest().unwrap();
+    assert_eq!(chest.entity.name, "Chest1");
+    assert!(interactables[1].as_door().is_none());
+}
+
+#[test]
+pub fn unique_ids_across_threads() {
+    example_prologue!("unique_ids_across_threads");
+
+    use std::collections::HashSet;
+    use std::thread;
+
+    const THREADS: usize = 4;
+    const PER_THREAD: usize = 50;
+
+    let spawn_weights = [(InteractableKind::Door, 1), (InteractableKind::Chest, 1)];
+
+    // Every thread spawns its own interactables and sends their ids back.
+    let handles = (0..THREADS)
+        .map(|t| {
+            thread::spawn(move || {
+                (0..PER_THREAD)
+                    .map(|i| spawn_random_interactable(format!("Interactable_{}_{}", t, i), &spawn_weights).id())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let ids = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+
+    // A set drops duplicates, so all the ids are unique if none got dropped.
+    let unique = ids.iter().collect::<HashSet<_>>();
+    assert_eq!(ids.len(), THREADS * PER_THREAD);
+    assert_eq!(unique.len(), ids.len());
+
+    // Ids are monotonically increasing, later ones are always greater.
+    let first = next_id();
+    assert!(next_id() > first);
+    assert!(ids.iter().all(|&id| id < first));
+}
+
+////// Operator overloading //////
+
+// Operators like +, - and * are syntactic sugar for the traits in std::ops, implementing them for our
+// own types lets those types be used with the operators just like the primitive numeric types.
+
+// A fixed-point decimal number with 3 decimal places, stored as an i64 scaled by 1000.
+// e.g. 1.5 is stored as 1500. Unlike floats, additions and subtractions are exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    pub const SCALE: i64 = 1000;
+}
+
+impl From<f64> for Fixed {
+    fn from(value: f64) -> Self {
+        Fixed((value * Self::SCALE as f64).round() as i64)
+    }
+}
+
+impl std::ops::Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, rhs: Fixed) -> Fixed {
+        // Multiplying two scaled values scales the result twice (by SCALE^2), so we divide by SCALE once.
+        // The division truncates, so we add half the scale beforehand (in the direction of the sign) to round
+        // half away from zero instead, e.g. 0.001 * 0.5 = 0.0005 becomes 0.001 rather than 0.000.
+        // The product is computed as i128 to not overflow on large values.
+        let product = self.0 as i128 * rhs.0 as i128;
+        let half = Self::SCALE as i128 / 2;
+        let rounded = if product >= 0 { product + half } else { product - half };
+        Fixed((rounded / Self::SCALE as i128) as i64)
+    }
+}
+
+impl std::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // The sign is printed separately since the integer part of e.g. -0.5 is 0, which has no sign.
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        let scale = Self::SCALE as u64;
+        write!(f, "{}{}.{:03}", sign, abs / scale, abs % scale)
+    }
+}
+
+#[test]
+pub fn operator_overloading() {
+    example_prologue!("operator_overloading");
+
+    let sum = Fixed::from(1.5) + Fixed::from(2.25);
+    println!("1.5 + 2.25 = {}", sum);
+    assert_eq!(sum.to_string(), "3.750");
+
+    assert_eq!((Fixed::from(1.0) - Fixed::from(1.5)).to_string(), "-0.500");
+    assert_eq!((Fixed::from(1.5) * Fixed::from(2.25)).to_string(), "3.375");
+
+    // 0.333 * 0.333 = 0.110889 which rounds up to 0.111.
+    assert_eq!((Fixed::from(0.333) * Fixed::from(0.333)).to_string(), "0.111");
+    // Exactly half way rounds away from zero.
+    assert_eq!((Fixed::from(0.001) * Fixed::from(0.5)).to_string(), "0.001");
+    assert_eq!((Fixed::from(-0.001) * Fixed::from(0.5)).to_string(), "-0.001");
+    // 0.001 * 0.4 = 0.0004 rounds down to 0.
+    assert_eq!(Fixed::from(0.001) * Fixed::from(0.4), Fixed::from(0.0));
+}
+
+////// Static dispatch via enums //////
+
+// When the set of implementing types is closed and known upfront (here only Door and Chest), wrapping them in an enum
+// is an alternative to Box<dyn Interaction>. Calls go through a match rather than a vtable lookup, each arm knowing
+// the concrete type so the call can be inlined, and the values are stored inline rather than behind a heap allocation each.
+// The tradeoff : adding a new interactable type means adding a variant and an arm to every match, whereas any type
+// implementing Interaction (even from another crate) can be boxed as a dyn Interaction without touching existing code.
+#[derive(Debug)]
+enum InteractableEnum {
+    Door(Door),
+    Chest(Chest),
+}
+
+impl InteractableEnum {
+    fn open(&self) {
+        match self {
+            InteractableEnum::Door(door) => door.open(),
+            InteractableEnum::Chest(chest) => chest.open(),
+        }
+    }
+
+    fn lock(&self) {
+        match self {
+            InteractableEnum::Door(door) => door.lock(),
+            InteractableEnum::Chest(chest) => chest.lock(),
+        }
+    }
+
+    fn id(&self) -> u64 {
+        match self {
+            InteractableEnum::Door(door) => door.id(),
+            InteractableEnum::Chest(chest) => chest.id(),
+        }
+    }
+}
+
+// Spawns the same interactables (same ids) both boxed as trait objects and wrapped in the enum, alternating doors and chests.
+fn spawn_both_ways(count: usize) -> (Vec<Box<dyn Interaction>>, Vec<InteractableEnum>) {
+    (0..count)
+        .map(|i| {
+            let id = next_id();
+            if i % 2 == 0 {
+                let door = || Door { id, entity: Entity::default() };
+                (Box::new(door()) as Box<dyn Interaction>, InteractableEnum::Door(door()))
+            } else {
+                let chest = || Chest { id, entity: Entity::default() };
+                (Box::new(chest()) as Box<dyn Interaction>, InteractableEnum::Chest(chest()))
+            }
+        })
+        .unzip()
+}
+
+#[test]
+pub fn enum_dispatch_same_behavior() {
+    example_prologue!("enum_dispatch_same_behavior");
+
+    let (boxed, enums) = spawn_both_ways(4);
+
+    // Both dispatch styles end up calling the very same Door and Chest implementations.
+    use crate::examples::capture::capture_stdout;
+    let dyn_output = capture_stdout(|| {
+        for interactable in &boxed {
+            interactable.open();
+            interactable.lock();
+        }
+    });
+    let enum_output = capture_stdout(|| {
+        for interactable in &enums {
+            interactable.open();
+            interactable.lock();
+        }
+    });
+
+    print!("{}", enum_output);
+    assert_eq!(dyn_output, enum_output);
+    assert!(enum_output.starts_with("Called the Door::Open function\nCalled the Door::lock function\n"));
+
+    assert_eq!(
+        boxed.iter().map(|interactable| interactable.id()).collect::<Vec<_>>(),
+        enums.iter().map(|interactable| interactable.id()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+pub fn enum_dispatch_timing() {
+    example_prologue!("enum_dispatch_timing");
+
+    // A rough comparison rather than a rigorous benchmark (see the benches folder for criterion based ones),
+    // black_box keeps the optimizer from computing the sums at compile time or skipping the loops altogether.
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    const COUNT: usize = 1_000_000;
+    let (boxed, enums) = spawn_both_ways(COUNT);
+
+    let start = Instant::now();
+    let dyn_sum: u64 = black_box(&boxed).iter().map(|interactable| interactable.id()).sum();
+    let dyn_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    let enum_sum: u64 = black_box(&enums).iter().map(|interactable| interactable.id()).sum();
+    let enum_elapsed = start.elapsed();
+
+    println!("Dynamic dispatch over {} interactables took {:?}", COUNT, dyn_elapsed);
+    println!("Enum dispatch over {} interactables took {:?}", COUNT, enum_elapsed);
+
+    // The timings vary from a run (and machine) to another so only the results are asserted.
+    assert_eq!(black_box(dyn_sum), black_box(enum_sum));
+}
+
+////// The newtype pattern //////
+
+// The orphan rule only allows implementing a trait for a type if either the trait or the type is local to our crate,
+// so that two crates can never provide conflicting implementations. Neither Display nor Vec are ours, which rules out
+// 'impl Display for Vec<String>', but wrapping the Vec in a local tuple struct (a newtype) makes the type ours.
+// The wrapper is zero cost at runtime, it has the exact same layout as the Vec it wraps.
+struct Wrapper(Vec<String>);
+
+impl std::fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(", ")) // the wrapped Vec is reachable through the tuple field.
+    }
+}
+
+#[test]
+pub fn newtype_orphan_rule() {
+    example_prologue!("newtype_orphan_rule");
+
+    let wrapper = Wrapper(vec!["Door1".to_owned(), "Chest1".to_owned(), "Door2".to_owned()]);
+    println!("Wrapped : {}", wrapper);
+
+    assert_eq!(wrapper.to_string(), "Door1, Chest1, Door2");
+    assert_eq!(Wrapper(vec![]).to_string(), "");
+}
@@ -12,6 +12,10 @@ use crate::*; //Import the entire crate.
 // Implement an unsafe trait
 // Access fields of unions
 
+// The FixedSizedStack along with its allocators lives in the library crate (src/fixed_sized_stack.rs)
+// so it can be reused and benchmarked outside of the examples.
+use crate::fixed_sized_stack::*;
+
 #[test]
 pub fn main() {
     example_prologue!("main");
@@ -26,127 +30,401 @@ pub fn main() {
     // we will also implement the Deref trait which allows to dereference the FixedSizedStack by the '*' operator
     // and access the data it contains by reference, and the Drop trait which allows to free the memory on lifetime expiration.
 
-    use libc::c_void; //https://crates.io/crates/libc
+    // Check out the FixedSizedStack declared in src/fixed_sized_stack.rs.
 
-    struct FixedSizedStack<T, const N: usize> {
-        // N is a constant generic parameter, you pass in a constant size.
-        pointer: *mut T, // this is the raw mutable pointer to the memory allocated on the heap.
-        curr_size: usize, //
-    }
+    const STACK_SIZE: usize = 10;
+    //instantiate a new FixedSizedStack instance by factory method new.
+    let mut stack = FixedSizedStack::<usize, STACK_SIZE>::new();
 
-    // Implement the Drop trait to free the memory on lifetime expiration.
-    impl<T, const N: usize> Drop for FixedSizedStack<T, N> {
-        fn drop(&mut self) {
-            println!("Freed the FixedSizedStack memory!");
-            self.free();
-        }
+    for i in 1..=STACK_SIZE {
+        // populate the stack with some data by iterating over the range 1..=STACK_SIZE.
+        stack.push(i);
+        println!("Pushed {}", i);
     }
-    //implement the Deref trait for our struct so that we can dereference it by the '*' operator.
-    impl<T, const N: usize> std::ops::Deref for FixedSizedStack<T, N> {
-        type Target = T;
-
-        fn deref(&self) -> &Self::Target {
-            unsafe {
-                //unsafe block required when dealing with raw pointers.
-                let offset = self.curr_size - 1;
-                self.pointer.add(offset).as_ref().unwrap() //As expected, it will panic if the reference is invalid.
+
+    println!("\n"); //newline
+
+    while !stack.empty() {
+        // iterate over the stack until it is empty.
+        let e = stack.pop();
+        unsafe {
+            if e != std::ptr::null() {
+                // only proceed if the element is not null.
+                if stack.empty() {
+                    println!("Popped {}, the stack is now empty.", *e);
+                } else {
+                    // if the stack is not empty, print the top of the stack element by dereferencing it
+                    println!("Popped {}, curr item is {}.", *e, *stack);
+                }
             }
         }
     }
+}
 
-    impl<T, const N: usize> FixedSizedStack<T, N> {
-        fn new() -> FixedSizedStack<T, N> {
-            //factory method to create a new FixedSizedStack instance.
-            unsafe {
-                //unsafe block required when dealing with raw pointers.
-                FixedSizedStack {
-                    pointer: libc::malloc(std::mem::size_of::<T>() * N) as *mut T, // allocate memory on the heap that fits the fixed stack size.
-                    curr_size: 0,
-                }
-            }
+// == Bump / Arena allocator ==
+// An arena grabs one big buffer upfront and hands out chunks of it by simply bumping an offset forward,
+// which makes allocating extremely cheap (no bookkeeping per allocation). Individual allocations can't be freed,
+// instead all of them are freed at once when the arena itself is dropped along with its buffer.
+// **Note that the destructors of the allocated values are never run, the memory is released as raw bytes,
+// so an arena is best suited for plain data types that don't own resources (e.g. heap memory or file handles).
+
+use std::cell::{Cell, UnsafeCell};
+
+pub struct Arena {
+    // UnsafeCell gives us interior mutability over the buffer, letting 'alloc' write to it through a shared
+    // reference (&self) so that multiple allocations can be alive at the same time.
+    buffer: UnsafeCell<Vec<u8>>,
+    offset: Cell<usize>, // Bump pointer, index of the first free byte in the buffer.
+}
+
+impl Arena {
+    pub fn new(capacity: usize) -> Arena {
+        // The buffer is never resized, resizing would move the allocations and leave dangling references behind.
+        Arena {
+            buffer: UnsafeCell::new(vec![0; capacity]),
+            offset: Cell::new(0),
         }
+    }
 
-        fn free(&mut self) -> bool {
-            unsafe {
-                if self.pointer == std::ptr::null_mut() {
-                    return false;
-                } // Guarantee no double freeing problems.
-                libc::free(self.pointer as *mut c_void); //free the memory allocated on the heap.
-                self.pointer = std::ptr::null_mut(); //set the pointer to null.
-                self.curr_size = 0;
-                true
+    // Moves 'value' into the arena and returns a mutable reference to it that lives as long as the arena.
+    // Panics if the arena doesn't have enough room left.
+    #[allow(clippy::mut_from_ref)] // Safe since every call hands out a distinct region of the buffer.
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        unsafe {
+            let buffer = &mut *self.buffer.get();
+            let base = buffer.as_mut_ptr();
+
+            // Round the next free address up to the alignment of T, reading a T from a misaligned address is UB.
+            let align = std::mem::align_of::<T>();
+            let start_addr = (base as usize + self.offset.get() + align - 1) & !(align - 1);
+            let start = start_addr - base as usize;
+            let end = start + std::mem::size_of::<T>();
+
+            if end > buffer.len() {
+                panic!("Arena is out of memory!");
             }
+
+            self.offset.set(end); // bump the pointer past the new allocation.
+
+            let ptr = base.add(start) as *mut T;
+            std::ptr::write(ptr, value); // move the value in without reading/dropping the old bytes.
+            &mut *ptr
         }
+    }
 
-        fn push(&mut self, value: *const T) {
-            //push element raw pointer T on the stack, which can be passed in as a reference.
+    // Number of bytes in use, including the alignment padding.
+    pub fn used(&self) -> usize {
+        self.offset.get()
+    }
+}
 
-            if self.curr_size >= N {
-                //bound checking
-                println!("Failed to push, Stack is full!");
-                return;
-            }
+#[test]
+pub fn arena_allocator() {
+    example_prologue!("arena_allocator");
 
-            unsafe {
-                //copy the value to the heap via native libc functions.
-                //ps: Copy trait is irrelevant here because we are copying data of raw pointer.
-                //so it doesn't matter if a struct that we are copying implements the copy trait.
-                libc::memcpy(
-                    (self.pointer.add(self.curr_size)) as *mut c_void,
-                    value as *mut c_void,
-                    std::mem::size_of::<T>(),
-                );
-
-                self.curr_size += 1; // increment size after pushing the element.
-            }
+    let arena = Arena::new(256);
+
+    let byte = arena.alloc(7u8);
+    let big = arena.alloc(0xDEAD_BEEF_u64);
+    let float = arena.alloc(3.5f32);
+    let pair = arena.alloc((-12i32, 'R'));
+    let array = arena.alloc([1u16, 2, 3]);
+
+    // All the references are alive at the same time and can be mutated independently.
+    *byte += 1;
+    pair.0 *= 2;
+
+    assert_eq!(*byte, 8);
+    assert_eq!(*big, 0xDEAD_BEEF);
+    assert_eq!(*float, 3.5);
+    assert_eq!(*pair, (-24, 'R'));
+    assert_eq!(*array, [1, 2, 3]);
+
+    // Every allocation is correctly aligned for its type.
+    assert_eq!(big as *const u64 as usize % std::mem::align_of::<u64>(), 0);
+    assert_eq!(pair as *const (i32, char) as usize % std::mem::align_of::<(i32, char)>(), 0);
+
+    println!("Arena used {} bytes out of 256", arena.used());
+} // arena drops here, freeing all of the above allocations at once.
+
+#[test]
+#[should_panic(expected = "Arena is out of memory!")]
+pub fn arena_out_of_memory() {
+    let arena = Arena::new(8);
+    arena.alloc(1u64);
+    arena.alloc(2u8); // no room left.
+}
+
+#[test]
+pub fn fixed_sized_stack_iter() {
+    example_prologue!("fixed_sized_stack_iter");
+
+    let mut stack = FixedSizedStack::<u32, 5>::new();
+    for i in [10, 20, 30] {
+        stack.push(i);
+    }
+
+    let first = stack.iter().collect::<Vec<_>>();
+    let second = stack.iter().copied().collect::<Vec<_>>();
+    println!("Stack content from bottom to top : {:?}", first);
+
+    // Both iterations agree and the stack is left untouched.
+    assert_eq!(first, vec![&10, &20, &30]);
+    assert_eq!(second, vec![10, 20, 30]);
+    assert_eq!(stack.iter().count(), 3);
+    assert_eq!(*stack, 30); // the top is still in place.
+
+    // Only the live elements are iterated, popped slots aren't.
+    stack.pop();
+    assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![10, 20]);
+}
+
+#[test]
+pub fn fixed_sized_stack_iter_owned() {
+    example_prologue!("fixed_sized_stack_iter_owned");
+
+    let mut stack = FixedSizedStack::<String, 4>::new();
+    let s = String::from("heap owned");
+    stack.push(s.clone());
+    drop(s); // the stack holds its own String, dropping the original doesn't affect it.
+
+    stack.push(String::from("second"));
+    let items = stack.iter().map(String::as_str).collect::<Vec<_>>();
+    println!("Stack content from bottom to top : {:?}", items);
+    assert_eq!(items, vec!["heap owned", "second"]);
+} // the stack drops both Strings along with its memory.
+
+#[test]
+pub fn fixed_sized_stack_allocators() {
+    example_prologue!("fixed_sized_stack_allocators");
+
+    // Drives the same fill/drain sequence through a stack regardless of the allocator backing it.
+    fn fill_and_drain<A: RawAllocator>(mut stack: FixedSizedStack<u64, 4, A>) -> Vec<u64> {
+        for i in 1..=5 {
+            stack.push(i * 100); // the 5th push is rejected, the stack is full.
         }
+        assert_eq!(stack.iter().count(), 4);
 
-        fn pop(&mut self) -> *const T {
-            // pop element T from the stack and return it as a raw pointer.
+        let mut drained = vec![];
+        while !stack.empty() {
+            drained.push(unsafe { *stack.pop() });
+        }
+        drained
+    }
 
-            if self.curr_size == 0 {
-                //Bound checking
-                println!("Failed to pop, Stack is empty!");
-                return std::ptr::null(); //return nullpointer if the stack is empty.
-            }
-            unsafe {
-                let offset = self.curr_size - 1;
-                let res = self.pointer.add(offset); // get top of the stack
-                self.curr_size -= 1; // decrement size after popping the element.
-                res // return the popped element.
-            }
+    // The allocator type can be inferred from the value passed to new_in, or named explicitly and defaulted by new.
+    let libc_drained = fill_and_drain(FixedSizedStack::new_in(LibcAllocator));
+    let std_drained = fill_and_drain(FixedSizedStack::<u64, 4, StdAllocator>::new());
+
+    println!("Drained libc backed stack : {:?}", libc_drained);
+    println!("Drained std backed stack : {:?}", std_drained);
+
+    assert_eq!(libc_drained, vec![400, 300, 200, 100]);
+    assert_eq!(libc_drained, std_drained);
+}
+
+#[test]
+pub fn fixed_sized_stack_over_aligned() {
+    example_prologue!("fixed_sized_stack_over_aligned");
+
+    // Aligned way past malloc's 16 bytes guarantee, e.g. to keep each value on its own cache line.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(align(256))]
+    struct CacheLine(u64);
+
+    fn check<A: RawAllocator + Default>() {
+        let mut stack = FixedSizedStack::<CacheLine, 4, A>::new();
+        for i in 0..4 {
+            stack.push(CacheLine(i));
+        }
+        for (i, line) in stack.iter().enumerate() {
+            assert_eq!(line as *const CacheLine as usize % 256, 0);
+            assert_eq!(*line, CacheLine(i as u64));
         }
+    }
+
+    check::<LibcAllocator>();
+    check::<StdAllocator>();
+}
+
+#[test]
+pub fn fixed_sized_stack_eq() {
+    example_prologue!("fixed_sized_stack_eq");
 
-        fn empty(&self) -> bool {
-            self.curr_size == 0
+    fn stack_of(values: &[i32]) -> FixedSizedStack<i32, 8> {
+        let mut stack = FixedSizedStack::<i32, 8>::new();
+        for value in values {
+            stack.push(*value);
         }
+        stack
     }
 
-    const STACK_SIZE: usize = 10;
-    //instantiate a new FixedSizedStack instance by factory method new.
-    let mut stack = FixedSizedStack::<usize, STACK_SIZE>::new();
+    // Equal stacks.
+    assert!(stack_of(&[1, 2, 3]) == stack_of(&[1, 2, 3]));
 
-    for i in 1..=STACK_SIZE {
-        // populate the stack with some data by iterating over the range 1..=STACK_SIZE.
-        stack.push(&i);
-        println!("Pushed {}", i);
+    // Different sizes, even though one is a prefix of the other.
+    assert!(stack_of(&[1, 2, 3]) != stack_of(&[1, 2]));
+
+    // Same sizes but different top elements.
+    assert!(stack_of(&[1, 2, 3]) != stack_of(&[1, 2, 4]));
+
+    // Popped values linger in the allocation but aren't live anymore, so they don't take part in the comparison.
+    let mut popped = stack_of(&[1, 2, 99]);
+    popped.pop();
+    assert!(popped == stack_of(&[1, 2]));
+
+    // Stacks backed by different allocators.
+    let mut std_backed = FixedSizedStack::<i32, 8, StdAllocator>::new();
+    std_backed.push(1);
+    assert!(stack_of(&[1]) == std_backed);
+
+    // Heap owning elements, each stack compares the Strings it owns.
+    let mut left = FixedSizedStack::<String, 2>::new();
+    let mut right = FixedSizedStack::<String, 2, StdAllocator>::new();
+    for word in ["a", "b"] {
+        let owned = word.to_string();
+        left.push(owned.clone());
+        right.push(owned);
     }
+    assert!(left == right);
+    // The popped String is owned by the caller now, taking it via ptr::read drops it at the end of the scope.
+    let popped = unsafe { std::ptr::read(right.pop()) };
+    assert_eq!(popped, "b");
+    right.push("c".to_string());
+    assert!(left != right);
+}
 
-    println!("\n"); //newline
+#[test]
+pub fn fixed_sized_stack_zero_sized() {
+    example_prologue!("fixed_sized_stack_zero_sized");
 
-    while !stack.empty() {
-        // iterate over the stack until it is empty.
-        let e = stack.pop();
-        unsafe {
-            if e != std::ptr::null() {
-                // only proceed if the element is not null.
-                if stack.empty() {
-                    println!("Popped {}, the stack is now empty.", *e);
-                } else {
-                    // if the stack is not empty, print the top of the stack element by dereferencing it
-                    println!("Popped {}, curr item is {}.", *e, *stack);
-                }
-            }
+    // '()' is a zero sized type, the stack doesn't allocate anything and only tracks its size.
+    let mut stack = FixedSizedStack::<(), 3>::new();
+    for _ in 0..3 {
+        assert!(stack.push(()));
+    }
+    assert!(!stack.push(())); // full at N.
+    assert_eq!(stack.iter().count(), 3);
+
+    for remaining in (0..3).rev() {
+        let popped = stack.pop();
+        assert!(!popped.is_null());
+        assert_eq!(unsafe { *popped }, ());
+        assert_eq!(stack.iter().count(), remaining);
+    }
+    assert!(stack.pop().is_null()); // empty.
+
+    // The same goes for the std allocator backed stack.
+    let mut stack = FixedSizedStack::<(), 2, StdAllocator>::new();
+    assert!(stack.push(()));
+    assert!(stack.free());
+}
+
+#[test]
+#[should_panic(expected = "Can't dereference an empty FixedSizedStack!")]
+pub fn fixed_sized_stack_deref_empty() {
+    let mut stack = FixedSizedStack::<u64, 4>::new();
+    stack.push(1);
+    stack.pop();
+    let _top = *stack; // nothing left to dereference.
+}
+
+#[test]
+pub fn fixed_sized_stack_zero_capacity() {
+    let mut stack = FixedSizedStack::<u64, 0>::new();
+
+    assert!(!stack.push(1)); // rejects the very first push.
+    assert!(stack.empty());
+    assert!(stack.pop().is_null());
+    assert_eq!(stack.iter().count(), 0);
+}
+
+#[test]
+pub fn fixed_sized_stack_into_vec() {
+    example_prologue!("fixed_sized_stack_into_vec");
+
+    let mut stack = FixedSizedStack::<i32, 8>::new();
+    assert_eq!(stack.extend_from_slice(&[1, 2, 3]), 3);
+    stack.push(4);
+
+    // Round trip, the Vec holds the elements from the bottom to the top of the stack.
+    let items = stack.into_vec();
+    println!("Stack into vec : {:?}", items);
+    assert_eq!(items, vec![1, 2, 3, 4]);
+
+    let mut stack = FixedSizedStack::<i32, 8>::new();
+    stack.extend_from_slice(&items);
+    assert_eq!(stack.into_vec(), items);
+
+    // Heap owning types work as well, extend_from_slice clones the items in and into_vec moves them out, so each
+    // String is only ever dropped once, by the Vec.
+    let words = vec![String::from("a"), String::from("b")];
+    let mut stack = FixedSizedStack::<String, 2, StdAllocator>::new();
+    assert_eq!(stack.extend_from_slice(&words), 2);
+    assert_eq!(stack.into_vec(), words);
+
+    // Counting the Rc references shows every clone is dropped exactly once, whether it's moved out into a Vec or
+    // left in the stack for its Drop to take care of.
+    let shared = std::rc::Rc::new(0);
+    let mut moved_out = FixedSizedStack::<std::rc::Rc<i32>, 4>::new();
+    let mut left_in = FixedSizedStack::<std::rc::Rc<i32>, 4>::new();
+    moved_out.extend_from_slice(&[shared.clone(), shared.clone()]);
+    left_in.extend_from_slice(&[shared.clone(), shared.clone()]);
+    assert_eq!(std::rc::Rc::strong_count(&shared), 5);
+    drop(moved_out.into_vec());
+    drop(left_in);
+    assert_eq!(std::rc::Rc::strong_count(&shared), 1);
+}
+
+#[test]
+pub fn fixed_sized_stack_extend_past_capacity() {
+    let mut stack = FixedSizedStack::<u8, 4>::new();
+
+    assert_eq!(stack.extend_from_slice(&[1, 2, 3]), 3);
+    // Only one more element fits.
+    assert_eq!(stack.extend_from_slice(&[4, 5, 6]), 1);
+    assert_eq!(stack.extend_from_slice(&[7]), 0);
+
+    assert_eq!(stack.into_vec(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+pub fn fixed_sized_stack_free_once() {
+    example_prologue!("fixed_sized_stack_free_once");
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // Delegates to the std allocator while counting the deallocations.
+    struct CountingAllocator {
+        deallocs: Rc<Cell<usize>>,
+    }
+
+    impl RawAllocator for CountingAllocator {
+        unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+            StdAllocator.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, pointer: *mut u8, layout: std::alloc::Layout) {
+            self.deallocs.set(self.deallocs.get() + 1);
+            StdAllocator.dealloc(pointer, layout);
         }
     }
+
+    let deallocs = Rc::new(Cell::new(0));
+    let mut stack = FixedSizedStack::<u64, 4, _>::new_in(CountingAllocator {
+        deallocs: Rc::clone(&deallocs),
+    });
+    stack.push(1);
+
+    assert!(stack.free()); // explicit free.
+    assert_eq!(deallocs.get(), 1);
+    assert!(!stack.free()); // freeing again is a no-op.
+    assert!(!stack.push(2)); // and so is pushing to a freed stack.
+    assert_eq!(stack.extend_from_slice(&[3, 4]), 0); // none of the items were pushed.
+    assert!(stack.empty());
+
+    drop(stack); // Drop calls free once more.
+    println!("Deallocated {} time(s)", deallocs.get());
+    assert_eq!(deallocs.get(), 1);
 }
@@ -0,0 +1,92 @@
+//Doc Ref : https://doc.rust-lang.org/reference/macros-by-example.html#textual-scope
+
+use crate::*; //Import the entire crate.
+
+//== Capturing the printed output of the examples ==
+//The examples print to stdout via print!/println!, to be able to assert on what they print, this module defines its own
+//print! and println! macros below. It is declared with #[macro_use] ahead of the other example modules (check out
+//main.rs), which brings its macros into the textual scope of those modules. Macros in textual scope take precedence over
+//the std prelude ones, so the examples pick these up instead without any change on their side.
+//**Note that they aren't #[macro_export]ed on purpose, that would put them at the crate root where the examples' glob
+//import (use crate::*;) would make them ambiguous with the std prelude ones.
+//They behave exactly like the std ones unless a capture_stdout call is in progress on the current thread, in which case
+//the output is appended to a thread local buffer instead.
+//**Note that the buffer being thread local, output printed from threads spawned by the captured function isn't captured.
+//**Note as well that only the code within the examples' modules picks up these macros, the library crate (lib.rs) is
+//compiled separately and keeps using the std ones, e.g. the messages printed by the FixedSizedStack's push/pop and Drop
+//(src/fixed_sized_stack.rs) always go straight to stdout.
+
+thread_local! {
+    static CAPTURED: std::cell::RefCell<Option<String>> = const { std::cell::RefCell::new(None) };
+}
+
+//Called by the print! and println! macros, not meant to be called directly.
+pub fn _print(args: std::fmt::Arguments) {
+    let captured = CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some(buffer) => {
+            std::fmt::Write::write_fmt(buffer, args).unwrap();
+            true
+        }
+        None => false,
+    });
+
+    if !captured {
+        std::print!("{}", args);
+    }
+}
+
+//Runs 'f' and returns everything it printed (via the crate's print!/println!) on the current thread.
+//The previous buffer is restored afterwards, so captures can be nested.
+pub fn capture_stdout<F: FnOnce()>(f: F) -> String {
+    let previous = CAPTURED.with(|captured| captured.replace(Some(String::new())));
+    f();
+    CAPTURED
+        .with(|captured| captured.replace(previous))
+        .unwrap_or_default()
+}
+
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::examples::capture::_print(format_args!($($arg)*))
+    };
+}
+
+macro_rules! println {
+    () => {
+        print!("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::examples::capture::_print(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+#[test]
+pub fn capture_example_output() {
+    example_prologue!("capture_example_output");
+
+    let output = capture_stdout(crate::examples::collections::collection_vec);
+
+    assert!(output.contains("collection_vec"));
+    let weapons = output
+        .lines()
+        .filter(|line| line.starts_with("Iterating over weapon"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        weapons,
+        vec![
+            "Iterating over weapon : AK47",
+            "Iterating over weapon : FAMAS",
+            "Iterating over weapon : P90",
+            "Iterating over weapon : SCAR",
+        ]
+    );
+
+    //Nothing is captured once the capture is over.
+    assert_eq!(capture_stdout(|| {}), "");
+    //Nested captures only see their own output.
+    let outer = capture_stdout(|| {
+        println!("outer");
+        assert_eq!(capture_stdout(|| print!("inner")), "inner");
+    });
+    assert_eq!(outer, "outer\n");
+}
@@ -11,6 +11,9 @@ Rust’s collections can be grouped into four major categories:
 // Examples will only be introducing the two most commonly used container types: Vec and HashMap.
 
 use crate::*; //Import the entire crate.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
 
 #[test]
 pub fn collection_vec() {
@@ -88,6 +91,8 @@ pub fn collection_hashmap() {
     //The following line of code uses the **zip** function which zips the values from both
     //iterators in parallel to form a new pair iterator. We finally call the **collect** function
     //to turn the iterator back into collection.
+    //**Note that zip stops as soon as either iterator runs out, so mismatched lengths would silently drop
+    //the extra keys or values, checkout 'zip_to_map' below for a version guarding against that.
     let mut weapons_db: HashMap<_, _> = inventory.iter().zip(prices.iter()).collect();
 
     //iterate the map (key,val) by reference.
@@ -119,4 +124,795 @@ pub fn collection_hashmap() {
     for (weapon, price) in &weapons_db {
         println!("The price of {} is {}", weapon, price);
     }
+    //The iteration order of a HashMap is arbitrary, util::format_table sorts the entries by key and aligns them.
+    println!("\nThe weapons db sorted by weapon name");
+    print!("{}", util::format_table(&weapons_db));
+}
+
+// Builds a map out of a vector of keys and a vector of values, pairing them by position.
+// Returns an error instead of silently truncating (like a plain zip would) when the lengths differ.
+pub fn zip_to_map<K: Eq + Hash, V>(keys: Vec<K>, values: Vec<V>) -> Result<HashMap<K, V>, String> {
+    if keys.len() != values.len() {
+        return Err(format!(
+            "Mismatched lengths: {} keys but {} values",
+            keys.len(),
+            values.len()
+        ));
+    }
+    Ok(keys.into_iter().zip(values).collect())
+}
+
+#[test]
+pub fn zip_to_map_equal_lengths() {
+    example_prologue!("zip_to_map_equal_lengths");
+
+    let weapons_db = zip_to_map(vec!["AK47", "FAMAS", "P90"], vec![3000, 25000, 2350]).unwrap();
+    println!("weapons db : {:?}", weapons_db);
+
+    assert_eq!(weapons_db.len(), 3);
+    assert_eq!(weapons_db["FAMAS"], 25000);
+}
+
+#[test]
+pub fn zip_to_map_mismatched_lengths() {
+    example_prologue!("zip_to_map_mismatched_lengths");
+
+    let result = zip_to_map(vec!["AK47", "FAMAS", "P90"], vec![3000, 25000]);
+    println!("result : {:?}", result);
+
+    assert_eq!(
+        result,
+        Err("Mismatched lengths: 3 keys but 2 values".to_owned())
+    );
+}
+
+// Generalizes the zip above, rather than collecting the pairs as is each of them is combined into a single value by 'f'.
+// Unlike zip_to_map, mismatched lengths are fine here, the result is as long as the shorter slice just like a zip.
+pub fn zip_with<A, B, R, F: Fn(&A, &B) -> R>(a: &[A], b: &[B], f: F) -> Vec<R> {
+    a.iter().zip(b).map(|(x, y)| f(x, y)).collect()
+}
+
+#[test]
+pub fn zip_with_sum() {
+    example_prologue!("zip_with_sum");
+
+    let base_prices = [3000, 25000, 2350];
+    let taxes = [300, 2500, 235];
+
+    let totals = zip_with(&base_prices, &taxes, |price, tax| price + tax);
+    println!("totals : {:?}", totals);
+
+    assert_eq!(totals, vec![3300, 27500, 2585]);
+}
+
+#[test]
+pub fn zip_with_truncates_to_shorter() {
+    example_prologue!("zip_with_truncates_to_shorter");
+
+    let inventory = ["AK47", "FAMAS", "P90", "M4A1"];
+    let prices = [3000, 25000];
+
+    let labels = zip_with(&inventory, &prices, |name, price| format!("{} : {}$", name, price));
+    println!("labels : {:?}", labels);
+
+    // The weapons without a price are left out.
+    assert_eq!(labels, vec!["AK47 : 3000$", "FAMAS : 25000$"]);
+    assert_eq!(zip_with(&prices, &inventory, |_, _| ()).len(), 2);
+}
+
+// Vectors that are kept sorted can be searched in O(log n) via the 'binary_search' method instead of
+// scanning every element. 'binary_search' returns a Result: Ok(index) if the value was found, or
+// Err(insertion_index) which is the position where the value could be inserted while keeping the order.
+
+// Searches the sorted vector for 'value', inserting it at its sorted position if it wasn't found.
+// The binary_search Result is returned as is so the caller knows whether an insertion took place.
+pub fn sorted_search(sorted: &mut Vec<i32>, value: i32) -> Result<usize, usize> {
+    let result = sorted.binary_search(&value);
+
+    if let Err(insertion_index) = result {
+        // The value wasn't found, inserting it at the suggested index keeps the vector sorted.
+        sorted.insert(insertion_index, value);
+    }
+
+    result
+}
+
+#[test]
+pub fn collection_binary_search() {
+    example_prologue!("collection_binary_search");
+
+    //Build a sorted vector of prices, 'sort' orders the elements in place.
+    let mut prices: Vec<i32> = vec![4000, 2350, 25000, 3000];
+    prices.sort();
+    println!("Sorted prices : {:?}", prices);
+
+    match sorted_search(&mut prices, 3000) {
+        Ok(index) => println!("Found 3000 at index {}", index),
+        Err(index) => println!("3000 wasn't found, inserted it at index {}", index),
+    }
+
+    match sorted_search(&mut prices, 2500) {
+        Ok(index) => println!("Found 2500 at index {}", index),
+        Err(index) => println!("2500 wasn't found, inserted it at index {}", index),
+    }
+
+    println!("Prices after searching : {:?}", prices);
+}
+
+#[test]
+pub fn sorted_search_found() {
+    let mut sorted = vec![1, 3, 5, 7, 9];
+
+    assert_eq!(sorted_search(&mut sorted, 7), Ok(3));
+    assert_eq!(sorted, vec![1, 3, 5, 7, 9]); // untouched since the value was found.
+}
+
+#[test]
+pub fn sorted_search_missing() {
+    let mut sorted = vec![1, 3, 5, 7, 9];
+
+    assert_eq!(sorted_search(&mut sorted, 4), Err(2));
+    assert_eq!(sorted_search(&mut vec![1, 3], 0), Err(0));
+    assert_eq!(sorted_search(&mut vec![1, 3], 10), Err(2));
+}
+
+#[test]
+pub fn sorted_search_inserts_in_order() {
+    let mut sorted = vec![10, 20, 30];
+
+    for value in [25, 5, 35, 20] {
+        let _ = sorted_search(&mut sorted, value); // the search result isn't needed, only the insertion.
+    }
+
+    assert_eq!(sorted, vec![5, 10, 20, 25, 30, 35]);
+}
+
+// A pool of active objects (e.g. bullets or particles in a game) that need to be created and destroyed often.
+// Removing from the middle of a Vec via 'remove' shifts every element after it (O(n)), whereas 'swap_remove'
+// moves the last element into the freed slot (O(1)) at the cost of not preserving the order.
+// **Note that releasing an object invalidates the index of the (previously) last object since it gets moved
+// into the released slot, so indices handed out by 'acquire' shouldn't be held onto across releases.
+#[derive(Debug, Default)]
+pub struct Pool<T> {
+    active: Vec<T>,
+}
+
+impl<T: Default> Pool<T> {
+    // Activates a new defaulted object and returns its index.
+    pub fn acquire(&mut self) -> usize {
+        self.active.push(T::default());
+        self.active.len() - 1
+    }
+}
+
+impl<T> Pool<T> {
+    // Deactivates the object at 'index' and returns it, the last object takes its place.
+    pub fn release(&mut self, index: usize) -> T {
+        self.active.swap_remove(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.active.get_mut(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.active.iter()
+    }
+}
+
+#[test]
+pub fn collection_pool() {
+    example_prologue!("collection_pool");
+
+    let mut bullets: Pool<String> = Pool::default();
+
+    for name in ["Bullet0", "Bullet1", "Bullet2", "Bullet3"] {
+        let index = bullets.acquire();
+        *bullets.get_mut(index).unwrap() = name.to_owned();
+    }
+    assert_eq!(bullets.len(), 4);
+
+    // Release a middle object, the last one (Bullet3) gets swapped into its slot.
+    let released = bullets.release(1);
+    println!("Released {}, active bullets : {:?}", released, bullets);
+
+    assert_eq!(released, "Bullet1");
+    assert_eq!(bullets.len(), 3);
+    assert_eq!(
+        bullets.iter().collect::<Vec<_>>(),
+        vec!["Bullet0", "Bullet3", "Bullet2"]
+    );
+
+    // Index 3 used to point to Bullet3, it is now out of bounds.
+    assert!(bullets.get_mut(3).is_none());
+}
+
+// Partitions the items into buckets keyed by the result of 'key_fn', preserving the items' relative order
+// within each bucket. The 'entry' API lets us fetch the bucket for a key, creating an empty one on first use.
+pub fn group_by<T, K, F>(items: Vec<T>, key_fn: F) -> HashMap<K, Vec<T>>
+where
+    F: Fn(&T) -> K,
+    K: Eq + Hash,
+{
+    let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+    for item in items {
+        groups.entry(key_fn(&item)).or_default().push(item);
+    }
+    groups
+}
+
+#[test]
+pub fn collection_group_by() {
+    example_prologue!("collection_group_by");
+
+    let words = vec!["apple", "banana", "avocado", "blueberry", "cherry"];
+    let groups = group_by(words, |word| word.chars().next().unwrap());
+    println!("Words grouped by first letter : {:?}", groups);
+
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[&'a'], vec!["apple", "avocado"]);
+    assert_eq!(groups[&'b'], vec!["banana", "blueberry"]);
+    assert_eq!(groups[&'c'], vec!["cherry"]);
+}
+
+// The safe Stack<T>, the Vec backed counterpart of FixedSizedStack, lives in the library crate (check out stack.rs)
+// so the benchmarks can compare the two.
+use crate::stack::Stack;
+
+#[test]
+pub fn collection_stack_from_iter() {
+    example_prologue!("collection_stack_from_iter");
+
+    let mut stack: Stack<i32> = (1..=5).collect();
+    println!("Collected stack : {:?}", stack);
+
+    assert_eq!(stack.len(), 5);
+    assert_eq!(stack.peek(), Some(&5)); // the last collected item is the top.
+
+    // Popping yields the items in the reverse order they were collected (LIFO).
+    let mut popped = vec![];
+    while let Some(value) = stack.pop() {
+        popped.push(value);
+    }
+    assert_eq!(popped, vec![5, 4, 3, 2, 1]);
+    assert!(stack.is_empty());
+}
+
+// A BinaryHeap is a priority queue, 'pop' always removes the greatest item according to the item's Ord impl.
+// The std BinaryHeap is a max-heap, so to pop the earliest task first the ordering of the tasks is reversed below.
+
+pub type Task = Box<dyn FnOnce() + Send>;
+
+struct ScheduledTask {
+    run_at: std::time::Instant,
+    seq: u64, // insertion order, so tasks scheduled at the same instant run in the order they were scheduled.
+    task: Task,
+}
+
+// Ord requires Eq which requires PartialEq, the task closure itself can't be compared so only the
+// scheduling keys (run_at, seq) take part in the comparisons.
+impl PartialEq for ScheduledTask {
+    fn eq(&self, other: &Self) -> bool {
+        (self.run_at, self.seq) == (other.run_at, other.seq)
+    }
+}
+
+impl Eq for ScheduledTask {}
+
+impl PartialOrd for ScheduledTask {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduledTask {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Reversed (other compared to self) to turn the max-heap into a min-heap.
+        (other.run_at, other.seq).cmp(&(self.run_at, self.seq))
+    }
+}
+
+#[derive(Default)]
+pub struct Scheduler {
+    tasks: std::collections::BinaryHeap<ScheduledTask>,
+    next_seq: u64,
+}
+
+impl Scheduler {
+    pub fn schedule<F: FnOnce() + Send + 'static>(&mut self, run_at: std::time::Instant, task: F) {
+        self.tasks.push(ScheduledTask {
+            run_at,
+            seq: self.next_seq,
+            task: Box::new(task),
+        });
+        self.next_seq += 1;
+    }
+
+    // Runs every task due at 'now' (or earlier) in time order, returns how many ran.
+    // Taking 'now' as a parameter rather than reading the clock keeps the scheduler deterministic and easy to test.
+    pub fn run_due(&mut self, now: std::time::Instant) -> usize {
+        let mut ran = 0;
+        // peek lets us check the earliest task without removing it, it stays queued if it isn't due yet.
+        while self.tasks.peek().is_some_and(|next| next.run_at <= now) {
+            let scheduled = self.tasks.pop().unwrap();
+            (scheduled.task)();
+            ran += 1;
+        }
+        ran
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+#[test]
+pub fn collection_binary_heap_scheduler() {
+    example_prologue!("collection_binary_heap_scheduler");
+
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut scheduler = Scheduler::default();
+
+    // Scheduled out of order on purpose.
+    for (name, ms) in [("third", 30), ("first", 10), ("second", 20)] {
+        let log = Arc::clone(&log);
+        scheduler.schedule(at(ms), move || log.lock().unwrap().push(name));
+    }
+
+    assert_eq!(scheduler.run_due(at(5)), 0); // nothing is due yet.
+    assert_eq!(scheduler.run_due(at(10)), 1);
+    assert_eq!(*log.lock().unwrap(), vec!["first"]);
+
+    assert_eq!(scheduler.run_due(at(100)), 2);
+    println!("Tasks ran in order : {:?}", log.lock().unwrap());
+    assert_eq!(*log.lock().unwrap(), vec!["first", "second", "third"]);
+    assert!(scheduler.is_empty());
+}
+
+// Counts the occurrences of every distinct item, the 'entry' API inserting a 0 count on an item's first occurrence.
+pub fn histogram<T: Eq + Hash>(items: impl IntoIterator<Item = T>) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for item in items {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Renders the histogram as one text bar per item, a '#' per occurrence, sorted by descending count.
+// A HashMap has no defined iteration order, so the items with equal counts are sorted by themselves to keep the output stable.
+pub fn format_histogram<T: Display + Ord>(counts: &HashMap<T, usize>) -> String {
+    let mut entries = counts.iter().collect::<Vec<_>>();
+    entries.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+    entries
+        .into_iter()
+        .map(|(item, &count)| format!("{} | {} ({})\n", item, "#".repeat(count), count))
+        .collect()
+}
+
+pub fn print_histogram<T: Display + Ord>(counts: &HashMap<T, usize>) {
+    print!("{}", format_histogram(counts));
+}
+
+#[test]
+pub fn collection_histogram() {
+    example_prologue!("collection_histogram");
+
+    let counts = histogram("hello world".chars().filter(|c| c.is_alphabetic()));
+    print_histogram(&counts);
+
+    assert_eq!(counts[&'l'], 3);
+    assert_eq!(counts[&'o'], 2);
+    assert_eq!(counts[&'h'], 1);
+    assert_eq!(counts.values().sum::<usize>(), 10);
+
+    let rendered = format_histogram(&counts);
+    let lines = rendered.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "l | ### (3)");
+    assert_eq!(lines[1], "o | ## (2)");
+    assert_eq!(lines[2], "d | # (1)");
+
+    // Every bar is as long as its count.
+    for line in lines {
+        let (item, bar) = line.split_once(" | ").unwrap();
+        let item = item.chars().next().unwrap();
+        assert_eq!(bar.chars().filter(|&c| c == '#').count(), counts[&item]);
+    }
+}
+
+// A Vec that keeps its elements sorted at all times, every insertion goes through binary_search to find the
+// position keeping the order (the same way sorted_search above does), which in turn allows 'contains' to binary
+// search as well, O(log n) rather than the O(n) scan of Vec::contains.
+// The inner Vec is private so it can only be mutated through 'insert', which guarantees the order is never broken.
+#[derive(Debug)]
+pub struct SortedVec<T: Ord> {
+    items: Vec<T>,
+}
+
+impl<T: Ord> Default for SortedVec<T> {
+    fn default() -> Self {
+        SortedVec { items: Vec::new() }
+    }
+}
+
+impl<T: Ord> SortedVec<T> {
+    // Duplicates are kept, binary_search finding an equal element returns its position which is as good as any.
+    pub fn insert(&mut self, value: T) {
+        let index = match self.items.binary_search(&value) {
+            Ok(index) | Err(index) => index,
+        };
+        self.items.insert(index, value);
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.items.binary_search(value).is_ok()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    // Yields the elements in sorted order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+#[test]
+pub fn collection_sorted_vec() {
+    example_prologue!("collection_sorted_vec");
+
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+
+    let mut sorted = SortedVec::default();
+    let mut inserted = vec![];
+
+    for _ in 0..100 {
+        let value = rng.gen_range(0..50);
+        sorted.insert(value);
+        inserted.push(value);
+
+        // Sorted after every single insertion.
+        assert!(sorted.iter().zip(sorted.iter().skip(1)).all(|(a, b)| a <= b));
+    }
+    assert_eq!(sorted.len(), 100);
+
+    for value in -1..51 {
+        assert_eq!(sorted.contains(&value), inserted.contains(&value));
+    }
+
+    inserted.sort();
+    assert_eq!(sorted.iter().copied().collect::<Vec<_>>(), inserted);
+}
+
+#[test]
+pub fn collection_sorted_vec_strings() {
+    let mut sorted = SortedVec::default();
+    for word in ["pear", "apple", "fig", "banana"] {
+        sorted.insert(word.to_owned());
+    }
+
+    assert_eq!(
+        sorted.iter().collect::<Vec<_>>(),
+        vec!["apple", "banana", "fig", "pear"]
+    );
+    assert!(sorted.contains(&"fig".to_owned()));
+    assert!(!sorted.contains(&"kiwi".to_owned()));
+}
+
+// A string interner stores each distinct string only once and hands out a compact symbol (its index in 'strings')
+// in its place, comparing or hashing two symbols is then as cheap as comparing two integers no matter how long the
+// strings are, which is why compilers intern identifiers this way.
+// The HashMap indexes the strings back to their symbols so that interning an already known string doesn't grow the Vec.
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    strings: Vec<String>,
+    symbols: HashMap<String, usize>,
+}
+
+impl StringInterner {
+    // Returns the symbol of 's', interning it first if it's seen for the first time.
+    pub fn intern(&mut self, s: &str) -> usize {
+        if let Some(&symbol) = self.symbols.get(s) {
+            return symbol;
+        }
+        let symbol = self.strings.len();
+        self.strings.push(s.to_owned());
+        self.symbols.insert(s.to_owned(), symbol);
+        symbol
+    }
+
+    // None if 'symbol' wasn't handed out by this interner.
+    pub fn resolve(&self, symbol: usize) -> Option<&str> {
+        self.strings.get(symbol).map(|s| s.as_str())
+    }
+
+    // Number of distinct strings interned so far.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[test]
+pub fn collection_string_interner() {
+    example_prologue!("collection_string_interner");
+
+    let mut interner = StringInterner::default();
+    let words = ["let", "x", "=", "x", "+", "let", "x"];
+
+    let symbols = words.iter().map(|word| interner.intern(word)).collect::<Vec<_>>();
+    println!("Symbols of {:?} : {:?}", words, symbols);
+
+    // Repeated strings share their symbol, only the distinct ones got stored.
+    assert_eq!(symbols, vec![0, 1, 2, 1, 3, 0, 1]);
+    assert_eq!(interner.len(), 4);
+
+    // Every symbol resolves back to the string it was handed out for.
+    for (word, symbol) in words.iter().zip(&symbols) {
+        assert_eq!(interner.resolve(*symbol), Some(*word));
+    }
+    assert_eq!(interner.resolve(4), None);
+}
+
+// A set of small integers (bit indices) where each one is a single bit of an array of u64 words, bit i lives in the
+// word i / 64 at the position i % 64. Compared to a HashSet<usize>, it takes 1 bit per possible element and every
+// operation is a couple of bitwise operations, at the cost of only holding indices below a fixed capacity.
+// **Note that the const parameter is the number of words rather than bits, the array length can't be computed from
+// a bit count on stable Rust ([u64; (BITS + 63) / 64] requires the unstable generic_const_exprs), so a BitSet<WORDS>
+// holds WORDS * 64 bits. 'words_for' does the rounding up for a given bit count, e.g. BitSet<{ words_for(100) }>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitSet<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+// Number of u64 words needed to hold 'bits' bits, const so it can be evaluated in a const generic argument.
+pub const fn words_for(bits: usize) -> usize {
+    bits.div_ceil(64)
+}
+
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        BitSet { words: [0; WORDS] }
+    }
+}
+
+impl<const WORDS: usize> BitSet<WORDS> {
+    pub const CAPACITY: usize = WORDS * 64;
+
+    // An empty set able to hold at least 'bits' bits, panics if WORDS is too small for them.
+    pub fn with_bits(bits: usize) -> Self {
+        assert!(
+            bits <= Self::CAPACITY,
+            "A BitSet of {} words holds {} bits, not {}",
+            WORDS,
+            Self::CAPACITY,
+            bits
+        );
+        Self::default()
+    }
+
+    // Splits a bit index into the index of its word and the mask selecting it within that word.
+    fn locate(bit: usize) -> (usize, u64) {
+        (bit / 64, 1 << (bit % 64))
+    }
+
+    // Panics if 'bit' is past the capacity, just like indexing past the end of an array.
+    pub fn set(&mut self, bit: usize) {
+        let (word, mask) = Self::locate(bit);
+        self.words[word] |= mask;
+    }
+
+    pub fn clear(&mut self, bit: usize) {
+        let (word, mask) = Self::locate(bit);
+        self.words[word] &= !mask;
+    }
+
+    // A bit past the capacity can't have been set, so it isn't contained.
+    pub fn contains(&self, bit: usize) -> bool {
+        let (word, mask) = Self::locate(bit);
+        self.words.get(word).is_some_and(|w| w & mask != 0)
+    }
+
+    // Number of set bits, count_ones is a single instruction (popcnt) on most CPUs.
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+}
+
+#[test]
+pub fn collection_bit_set() {
+    example_prologue!("collection_bit_set");
+
+    // 3 words, 192 bits.
+    let mut bits = BitSet::<{ words_for(192) }>::with_bits(192);
+    assert_eq!(BitSet::<3>::CAPACITY, 192);
+    assert_eq!(words_for(1), 1);
+    assert_eq!(words_for(129), 3); // rounded up.
+
+    // Bits on both sides of the word boundaries (63 | 64 and 127 | 128) as well as the very first and last ones.
+    let set = [0, 63, 64, 100, 127, 128, 191];
+    for bit in set {
+        bits.set(bit);
+    }
+    bits.set(64); // setting a bit twice doesn't change anything.
+    println!("Bit set words : {:?}", bits);
+
+    assert_eq!(bits.count_ones(), set.len() as u32);
+    assert!((0..BitSet::<3>::CAPACITY).all(|bit| bits.contains(bit) == set.contains(&bit)));
+    assert!(!bits.contains(192)); // past the capacity.
+
+    // Clearing only affects the given bit, including its neighbours across the boundary.
+    bits.clear(64);
+    assert!(!bits.contains(64));
+    assert!(bits.contains(63) && bits.contains(100));
+    assert_eq!(bits.count_ones(), set.len() as u32 - 1);
+
+    bits.clear(64); // clearing an unset bit is a no-op.
+    assert_eq!(bits.count_ones(), set.len() as u32 - 1);
+}
+
+#[test]
+#[should_panic]
+pub fn collection_bit_set_out_of_range() {
+    BitSet::<1>::default().set(64);
+}
+
+#[test]
+#[should_panic(expected = "A BitSet of 1 words holds 64 bits, not 65")]
+pub fn collection_bit_set_too_small() {
+    BitSet::<1>::with_bits(65);
+}
+
+// A directed graph over the nodes 0..n stored as adjacency lists, edges[node] holding the nodes it points to.
+#[derive(Debug, Default)]
+pub struct Graph {
+    edges: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    pub fn new(nodes: usize) -> Self {
+        Graph {
+            edges: vec![Vec::new(); nodes],
+        }
+    }
+
+    // Adds an edge from 'from' to 'to', i.e. 'from' has to come before 'to' in a topological ordering.
+    // Both ends have to be nodes of the graph, an edge to a missing node would otherwise only blow up later on.
+    pub fn add_edge(&mut self, from: usize, to: usize) -> Result<(), String> {
+        let nodes = self.edges.len();
+        if from >= nodes || to >= nodes {
+            return Err(format!(
+                "Invalid edge {} -> {}, the graph only has the nodes 0..{}",
+                from, to, nodes
+            ));
+        }
+        self.edges[from].push(to);
+        Ok(())
+    }
+
+    // Orders the nodes so that every node comes before the nodes its edges point to, using Kahn's algorithm :
+    // the in-degree of a node is the number of edges pointing to it, the nodes with none are ready to be output,
+    // outputting one removes its edges which decrements the in-degree of the nodes they point to, readying those that
+    // reach 0. Nodes that never become ready are part of a cycle (each waits on another), hence the error.
+    // Many orderings are usually valid, always picking the lowest ready node (the Reverse turns the max-heap into a
+    // min-heap, check the Scheduler above) makes the result deterministic, a graph without edges keeps its node order.
+    pub fn topo_sort(&self) -> Result<Vec<usize>, String> {
+        use std::cmp::Reverse;
+
+        let mut in_degrees = vec![0; self.edges.len()];
+        for &to in self.edges.iter().flatten() {
+            in_degrees[to] += 1;
+        }
+
+        let mut ready = (0..self.edges.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .map(Reverse)
+            .collect::<std::collections::BinaryHeap<_>>();
+        let mut order = Vec::with_capacity(self.edges.len());
+
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(node);
+            for &to in &self.edges[node] {
+                in_degrees[to] -= 1;
+                if in_degrees[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+
+        if order.len() < self.edges.len() {
+            return Err(format!(
+                "The graph has a cycle, {} of its {} nodes couldn't be ordered",
+                self.edges.len() - order.len(),
+                self.edges.len()
+            ));
+        }
+        Ok(order)
+    }
+}
+
+#[test]
+pub fn collection_graph_topo_sort() {
+    example_prologue!("collection_graph_topo_sort");
+
+    // Build steps : 0 fetch, 1 configure, 2 compile, 3 test, 4 package, 5 docs.
+    let edges = [(0, 1), (1, 2), (2, 3), (2, 4), (3, 4), (0, 5)];
+    let mut graph = Graph::new(6);
+    for (from, to) in edges {
+        graph.add_edge(from, to).unwrap();
+    }
+
+    let order = graph.topo_sort().unwrap();
+    println!("Build order : {:?}", order);
+
+    // Every node comes before the nodes it points to.
+    let position = |node| order.iter().position(|&n| n == node).unwrap();
+    assert!(edges.iter().all(|&(from, to)| position(from) < position(to)));
+    assert_eq!(order, vec![0, 1, 2, 3, 4, 5]);
+
+    assert_eq!(Graph::new(3).topo_sort(), Ok(vec![0, 1, 2]));
+}
+
+#[test]
+pub fn collection_graph_topo_sort_cycle() {
+    example_prologue!("collection_graph_topo_sort_cycle");
+
+    // 1 -> 2 -> 3 -> 1 is a cycle, 0 -> 1 leads into it.
+    let mut graph = Graph::new(4);
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 1)] {
+        graph.add_edge(from, to).unwrap();
+    }
+
+    let result = graph.topo_sort();
+    println!("Result : {:?}", result);
+    assert_eq!(
+        result,
+        Err("The graph has a cycle, 3 of its 4 nodes couldn't be ordered".to_owned())
+    );
+
+    // A node pointing to itself is a cycle as well.
+    let mut graph = Graph::new(1);
+    graph.add_edge(0, 0).unwrap();
+    assert!(graph.topo_sort().is_err());
+}
+
+#[test]
+pub fn collection_graph_invalid_edge() {
+    let mut graph = Graph::new(2);
+
+    assert_eq!(
+        graph.add_edge(0, 99),
+        Err("Invalid edge 0 -> 99, the graph only has the nodes 0..2".to_owned())
+    );
+    assert!(graph.add_edge(2, 0).is_err());
+
+    // The rejected edges weren't added, the graph is still sortable.
+    assert_eq!(graph.topo_sort(), Ok(vec![0, 1]));
 }
@@ -188,3 +188,147 @@ pub fn if_and_while_let_expr() {
         println!("n = {}", n);
     }
 }
+
+// Recursion vs loops.
+// Every recursive call pushes a new frame onto the thread's call stack, which has a limited size (8MB by default
+// for the main thread on most platforms, 2MB for spawned threads). A recursion that goes deep enough overflows it
+// and aborts the process, Rust doesn't guarantee tail call optimization so this holds even for tail calls.
+// A loop on the other hand runs in a single frame regardless of the number of iterations.
+
+// Recursive version, n nested calls deep.
+pub fn factorial_recursive(n: u64) -> u64 {
+    if n <= 1 {
+        1
+    } else {
+        n * factorial_recursive(n - 1)
+    }
+}
+
+// Iterative version, the same computation in a constant amount of stack space.
+pub fn factorial_iterative(n: u64) -> u64 {
+    let mut result = 1;
+    for i in 2..=n {
+        result *= i;
+    }
+    result
+}
+
+// Both of the above overflow u64 past n = 20 (a panic in debug builds and a silent wrap around in release builds).
+// checked_mul returns None instead on overflow, the ? operator then returns that None right away.
+pub fn factorial_checked(n: u64) -> Option<u64> {
+    let mut result: u64 = 1;
+    for i in 2..=n {
+        result = result.checked_mul(i)?;
+    }
+    Some(result)
+}
+
+#[test]
+pub fn recursion_vs_loop() {
+    example_prologue!("recursion_vs_loop");
+
+    for n in 0..=20 {
+        assert_eq!(factorial_recursive(n), factorial_iterative(n));
+    }
+    println!("10! = {}", factorial_iterative(10));
+    assert_eq!(factorial_iterative(10), 3628800);
+}
+
+#[test]
+pub fn factorial_overflow() {
+    example_prologue!("factorial_overflow");
+
+    // 20! is the largest factorial fitting in a u64.
+    assert_eq!(factorial_checked(20), Some(factorial_iterative(20)));
+    assert_eq!(factorial_checked(21), None);
+    assert_eq!(factorial_checked(25), None);
+}
+
+// Sums the depths of 'depth' nested levels (depth + (depth - 1) + ... + 1), one recursive call per level.
+// Every level takes up a stack frame, so past a certain depth the thread's stack runs out and the process aborts
+// with a "stack overflow" fatal error (it isn't a panic, so it can't be caught). Where exactly depends on the frame
+// size (larger in debug builds) and the stack size, a spawned thread's 2MB stack overflows after tens of thousands of levels.
+// **The black_box keeps the optimizer from turning the recursion into a loop, which it would otherwise happily do
+// in release builds hiding the issue.
+pub fn sum_nested_depth(depth: usize) -> u64 {
+    if depth == 0 {
+        return 0;
+    }
+    std::hint::black_box((depth as u64).saturating_add(sum_nested_depth(depth - 1)))
+}
+
+// The same sum computed in a loop, it takes a single stack frame whatever the depth.
+pub fn sum_nested_depth_iterative(depth: usize) -> u64 {
+    let mut sum: u64 = 0;
+    for level in 1..=depth {
+        sum = sum.saturating_add(level as u64);
+    }
+    sum
+}
+
+#[test]
+pub fn nested_depth_recursive_vs_iterative() {
+    example_prologue!("nested_depth_recursive_vs_iterative");
+
+    for depth in [0, 1, 10, 1000] {
+        assert_eq!(sum_nested_depth(depth), sum_nested_depth_iterative(depth));
+    }
+    assert_eq!(sum_nested_depth_iterative(1000), 500500);
+
+    // The iterative version handles any depth, kept at a million here so the test stays fast in debug builds
+    // (the depth that overflows the recursive version is left to the ignored test below).
+    assert_eq!(sum_nested_depth_iterative(1_000_000), 500_000_500_000);
+}
+
+// Run explicitly via "cargo test nested_depth_overflow -- --ignored".
+// **Note that the stack overflow aborts the whole test process rather than failing this test alone.
+#[test]
+#[ignore]
+pub fn nested_depth_overflow() {
+    example_prologue!("nested_depth_overflow");
+
+    sum_nested_depth(100_000_000);
+}
+
+// The naive recursive Fibonacci recomputes the same values over and over, fib(n - 1) and fib(n - 2) both recompute
+// fib(n - 3) and so on, which makes for an exponential number of calls (fib(40) takes hundreds of millions of calls).
+pub fn fib_naive(n: u64) -> u64 {
+    if n < 2 {
+        n
+    } else {
+        fib_naive(n - 1) + fib_naive(n - 2)
+    }
+}
+
+// Memoization caches every computed value so each one is only ever computed once, bringing it down to n calls.
+// The cache is passed in by mutable reference so it can be shared (and inspected) across calls.
+pub fn fib_memo(n: u64, cache: &mut std::collections::HashMap<u64, u64>) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    if let Some(&value) = cache.get(&n) {
+        return value;
+    }
+    let value = fib_memo(n - 1, cache) + fib_memo(n - 2, cache);
+    cache.insert(n, value);
+    value
+}
+
+#[test]
+pub fn memoized_fibonacci() {
+    example_prologue!("memoized_fibonacci");
+
+    let mut cache = std::collections::HashMap::new();
+    for n in 0..=20 {
+        assert_eq!(fib_memo(n, &mut cache), fib_naive(n));
+    }
+
+    // Every value from 2 to n ends up cached (0 and 1 are the base cases and are never cached).
+    let mut cache = std::collections::HashMap::new();
+    assert_eq!(fib_memo(30, &mut cache), 832040);
+    assert_eq!(cache.len(), 29);
+
+    // fib(90) would take the naive version ages, the memoized one returns instantly.
+    println!("fib(90) = {}", fib_memo(90, &mut cache));
+    assert_eq!(fib_memo(90, &mut cache), 2880067194370816120);
+}
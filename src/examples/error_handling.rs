@@ -39,6 +39,35 @@ pub fn panic_invalid_memory() {
     v[99];
 }
 
+//The recoverable companion of the above, 'get' returns an Option rather than panicking on an out of bounds index,
+//which we turn into a Result carrying a descriptive error that the caller gets to handle (or propagate).
+pub fn get_or_err(v: &[i32], index: usize) -> Result<i32, String> {
+    v.get(index).copied().ok_or_else(|| {
+        format!(
+            "Index {} is out of bounds for a slice of length {}",
+            index,
+            v.len()
+        )
+    })
+}
+
+#[test]
+pub fn recoverable_invalid_index() {
+    example_prologue!("Recoverable invalid index");
+
+    let v = vec![1, 2, 3];
+
+    assert_eq!(get_or_err(&v, 1), Ok(2));
+
+    //No panic this time, just an error we can inspect and recover from.
+    let result = get_or_err(&v, 99);
+    println!("get_or_err(&v, 99) = {:?}", result);
+    assert_eq!(
+        result,
+        Err("Index 99 is out of bounds for a slice of length 3".to_owned())
+    );
+}
+
 pub struct Result_Handling {
     // Most errors aren’t serious enough to require the program to stop entirely.
     // Sometimes, when a function fails, it’s for a reason that you can easily interpret and respond to.
@@ -178,4 +207,43 @@ const FILE_NAME: &str = "sample.txt";
                 None => "Invalid".to_owned(),
             }
         )
-    }
\ No newline at end of file
+    }
+
+//Results in iterator chains : collecting an iterator of Results into a Result<Vec<T>, E> (via collect::<Result<Vec<_>, _>>())
+//stops at the first error and returns it, otherwise all of the Ok values are returned in a Vec.
+//The following does the same thing written out explicitly, the ? operator returns the error early out of the loop
+//so the rest of the iterator is never consumed.
+pub fn collect_results<T, E>(iter: impl Iterator<Item = Result<T, E>>) -> Result<Vec<T>, E> {
+    let mut collected = Vec::new();
+    for item in iter {
+        collected.push(item?);
+    }
+    Ok(collected)
+}
+
+#[test]
+pub fn collect_results_all_ok() {
+    example_prologue!("collect_results_all_ok");
+
+    let parsed = collect_results(["1", "2", "3"].iter().map(|s| s.parse::<i32>()));
+    println!("Parsed : {:?}", parsed);
+
+    assert_eq!(parsed, Ok(vec![1, 2, 3]));
+    //Same outcome as the std collect.
+    assert_eq!(parsed, ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect());
+}
+
+#[test]
+pub fn collect_results_short_circuits() {
+    example_prologue!("collect_results_short_circuits");
+
+    let mut consumed = 0;
+    let items = [Ok(1), Err("second failed"), Err("third failed"), Ok(4)];
+
+    let result = collect_results(items.iter().inspect(|_| consumed += 1).cloned());
+    println!("Result : {:?}", result);
+
+    //Only the first error is reported, and nothing past it was consumed.
+    assert_eq!(result, Err("second failed"));
+    assert_eq!(consumed, 2);
+}
@@ -18,4 +18,76 @@ macro_rules! example_prologue {
         println!("============================\n");
     };
     
-}
\ No newline at end of file
+}
+
+//define_instrument generates an instrument struct along with its Instrument trait impl (check out the modules example),
+//sparing every instrument file from repeating the same boilerplate.
+//It takes the struct name, the message printed when played, and the InstrumentVisitor method of the instrument's family.
+//e.g. define_instrument!(Guitar, "Playing Guitar", visit_string);
+//**Note the use of $crate which always resolves to the crate defining the macro, so the generated code finds the
+//Instrument traits no matter which module the macro is invoked from.
+#[macro_export]
+macro_rules! define_instrument {
+
+    ($name:ident, $message:expr, $visit:ident) => {
+        #[derive(Default, Debug)]
+        pub struct $name {}
+
+        impl $crate::examples::modules::instruments::instrument::Instrument for $name {
+            fn play(&self) {
+                println!("{}", $message);
+            }
+
+            fn accept(
+                &self,
+                visitor: &mut dyn $crate::examples::modules::instruments::instrument::InstrumentVisitor,
+            ) {
+                visitor.$visit(self);
+            }
+        }
+    };
+}
+
+//builder generates a struct along with a builder type offering a chainable setter per field and a 'build' method.
+//Every field is stored as an Option inside the builder until 'build' is called, so omitting a field isn't a
+//compile-time error but is handled by 'build' returning an Err naming the first missing field.
+//e.g. builder!(User => UserBuilder { username: String, active: bool });
+//     let user = User::builder().username("rusty".to_owned()).active(true).build();
+//The $( ... ),* syntax repeats its content for every matched field, that's how a single field list
+//expands into the struct fields, the builder fields, the setters and the build checks alike.
+#[macro_export]
+macro_rules! builder {
+
+    ($name:ident => $builder:ident { $($field:ident : $ty:ty),* $(,)? }) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $(pub $field: $ty),*
+        }
+
+        #[derive(Debug, Default)]
+        pub struct $builder {
+            $($field: Option<$ty>),*
+        }
+
+        impl $name {
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $builder {
+            $(
+                pub fn $field(mut self, value: $ty) -> Self {
+                    self.$field = Some(value);
+                    self
+                }
+            )*
+
+            pub fn build(self) -> Result<$name, String> {
+                Ok($name {
+                    $($field: self.$field.ok_or_else(|| format!("missing field '{}'", stringify!($field)))?),*
+                })
+            }
+        }
+    };
+}
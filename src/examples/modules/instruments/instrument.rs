@@ -1,3 +1,14 @@
 pub trait Instrument {
     fn play(&self);
-}
\ No newline at end of file
+
+    // Double dispatch entry point of the visitor pattern, every instrument calls back into the
+    // visitor method matching its own family.
+    fn accept(&self, visitor: &mut dyn InstrumentVisitor);
+}
+
+// A visitor defines an operation per instrument family without having to touch the instruments themselves.
+pub trait InstrumentVisitor {
+    fn visit_string(&mut self, instrument: &dyn Instrument);
+    fn visit_percussion(&mut self, instrument: &dyn Instrument);
+    fn visit_wind(&mut self, instrument: &dyn Instrument);
+}
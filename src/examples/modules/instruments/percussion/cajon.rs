@@ -1,5 +1,5 @@
 //absolute path from crate root.
-use crate::examples::modules::instruments::instrument::Instrument;
+use crate::examples::modules::instruments::instrument::{Instrument, InstrumentVisitor};
 
 #[derive(Default, Debug)]
 pub struct Cajon {}
@@ -10,4 +10,8 @@ impl Instrument for Cajon {
     fn play(&self) {
         println!("Playing {:?}", self);
     }
+
+    fn accept(&self, visitor: &mut dyn InstrumentVisitor) {
+        visitor.visit_percussion(self);
+    }
 }
\ No newline at end of file
@@ -1,13 +1,2 @@
-//absolute path from crate root.
-use crate::examples::modules::instruments::instrument::Instrument;
-
-#[derive(Default, Debug)]
-pub struct Guitar {}
-
- 
-impl Instrument for Guitar {
-
-    fn play(&self) {
-        println!("Playing {:?}", self);
-    }
-}
\ No newline at end of file
+//The struct and its Instrument impl are generated by the define_instrument macro (check out macros.rs).
+crate::define_instrument!(Guitar, "Playing Guitar", visit_string);
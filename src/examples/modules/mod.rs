@@ -46,7 +46,7 @@ pub mod instruments {
 
 //This is an Absolute path starting from the crate root file (main.rs for bin or lib.rs for libs).
 use crate::*; //Import the entire crate.
-use examples::modules::instruments::instrument::Instrument;
+use examples::modules::instruments::instrument::{Instrument, InstrumentVisitor};
 
 //This is a relative path from the current module's path.
 use instruments::percussion::{cajon::Cajon, drums::Drums};
@@ -56,14 +56,10 @@ use instruments::wind::{
     wood::{clarinet::Clarinet, flute::Flute},
 };
 
-#[test]
-pub fn main() {
-
-    example_prologue!("Modules Demo - Playing instruments");
-
-    //We create an array of instruments and iterate over them and call the play function.
-    //PS: Box type is a wrapper to give size to non sized things like traits, we will discuss it in a later chapter.
-    let instruments: [Box<dyn Instrument>; 9] = [
+//Creates an array holding one instance of every instrument.
+//PS: Box type is a wrapper to give size to non sized things like traits, we will discuss it in a later chapter.
+fn demo_instruments() -> [Box<dyn Instrument>; 9] {
+    [
         Box::new(Guitar::default()),
         Box::new(Cello::default()),
         Box::new(Violin::default()),
@@ -73,9 +69,105 @@ pub fn main() {
         Box::new(Trumpet::default()),
         Box::new(Clarinet::default()),
         Box::new(Flute::default()),
-    ];
+    ]
+}
+
+#[test]
+pub fn main() {
+
+    example_prologue!("Modules Demo - Playing instruments");
+
+    //We create an array of instruments and iterate over them and call the play function.
+    let instruments = demo_instruments();
 
     for inst in instruments {
         inst.play();
     }
 }
+
+// The visitor pattern allows us to add new operations over a family of types without modifying them, each
+// instrument 'accepts' a visitor and calls back into the visitor method of its own family (double dispatch).
+#[derive(Default, Debug)]
+struct CountingVisitor {
+    string: usize,
+    percussion: usize,
+    wind: usize,
+}
+
+impl InstrumentVisitor for CountingVisitor {
+    fn visit_string(&mut self, _: &dyn Instrument) {
+        self.string += 1;
+    }
+
+    fn visit_percussion(&mut self, _: &dyn Instrument) {
+        self.percussion += 1;
+    }
+
+    fn visit_wind(&mut self, _: &dyn Instrument) {
+        self.wind += 1;
+    }
+}
+
+#[test]
+pub fn visitor() {
+    example_prologue!("Modules Demo - Counting instruments by family");
+
+    let mut counter = CountingVisitor::default();
+
+    for inst in demo_instruments().iter() {
+        inst.accept(&mut counter);
+    }
+
+    println!("Instruments per family : {:?}", counter);
+
+    assert_eq!(counter.string, 3);
+    assert_eq!(counter.percussion, 2);
+    assert_eq!(counter.wind, 4);
+}
+
+#[test]
+pub fn macro_generated_instrument() {
+    example_prologue!("Modules Demo - Macro generated instruments");
+
+    //A hand-written instrument, the way the rest of the instrument files are implemented.
+    #[derive(Default, Debug)]
+    struct Banjo {}
+
+    impl Instrument for Banjo {
+        fn play(&self) {
+            println!("Playing {:?}", self);
+        }
+
+        fn accept(&self, visitor: &mut dyn InstrumentVisitor) {
+            visitor.visit_string(self);
+        }
+    }
+
+    //The same instrument generated by the define_instrument macro.
+    define_instrument!(Ukulele, "Playing Ukulele", visit_string);
+
+    let instruments: [Box<dyn Instrument>; 2] = [Box::new(Banjo::default()), Box::new(Ukulele::default())];
+
+    //Both are used through the trait object the exact same way.
+    let mut counter = CountingVisitor::default();
+    for inst in instruments.iter() {
+        inst.play();
+        inst.accept(&mut counter);
+    }
+
+    assert_eq!(counter.string, 2);
+    assert_eq!(counter.percussion + counter.wind, 0);
+    assert_eq!(format!("{:?}", Ukulele::default()), "Ukulele");
+
+    //The generated play() prints the exact same way as the hand-written one.
+    let banjo = crate::examples::capture::capture_stdout(|| Banjo::default().play());
+    let ukulele = crate::examples::capture::capture_stdout(|| Ukulele::default().play());
+    assert_eq!(banjo, "Playing Banjo\n");
+    assert_eq!(ukulele, "Playing Ukulele\n");
+    assert_eq!(banjo.replace("Banjo", "Ukulele"), ukulele);
+
+    //The refactored Guitar is dispatched to the same family as before.
+    let mut counter = CountingVisitor::default();
+    Guitar::default().accept(&mut counter);
+    assert_eq!(counter.string, 1);
+}
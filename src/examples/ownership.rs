@@ -29,6 +29,7 @@ pub fn assignment_ownership() {
     println!("s2 = {}", s2);
     //using s1 at this point will cause a compile-time error, uncomment the following line to try it:
     //println!("s1 = {}", s1);
+    //(enforced by tests/compile_fail/use_after_move.rs)
 
     //if we want to perform a deep copy of s1 into s2, we should call the clone function instead.
     let s1 = String::from("hello");
@@ -65,6 +66,7 @@ pub fn function_ownership() {
 
     //Uncommenting the following line will result in a compilation error.
     //println!("s = {}", s);
+    //(enforced by tests/compile_fail/moved_into_function.rs)
 
     let x = 5; // x comes into scope
 
@@ -132,6 +134,7 @@ pub fn refs_and_burrowing() {
 
     //Uncommenting the following line will result in a compilation error because there can only be one mutable reference to the same piece of data at a time.
     //println!("ms1 = {}", ms1);
+    //(enforced by tests/compile_fail/double_mut_borrow.rs)
 }
 
 #[test]
@@ -167,6 +170,142 @@ pub fn slice_type() {
     
 }
 
+// Computes the moving average over every window of 'window' consecutive elements of the slice.
+// The 'windows' method hands out overlapping sub-slices borrowing from 'data' (no copies), e.g. a window of 2
+// over [1, 2, 3] yields [1, 2] then [2, 3]. An empty vector is returned when no window fits (or window is 0,
+// which 'windows' would otherwise panic on).
+pub fn windowed_average(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+    data.windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+#[test]
+pub fn windowed_average_normal() {
+    example_prologue!("windowed_average_normal");
+
+    let averages = windowed_average(&[1.0, 2.0, 3.0, 4.0, 5.0], 3);
+    println!("Moving averages : {:?}", averages);
+    assert_eq!(averages, vec![2.0, 3.0, 4.0]);
+}
+
+#[test]
+pub fn windowed_average_full_length() {
+    example_prologue!("windowed_average_full_length");
+
+    // A single window spanning the whole slice.
+    assert_eq!(windowed_average(&[1.0, 2.0, 6.0], 3), vec![3.0]);
+}
+
+#[test]
+pub fn windowed_average_invalid_window() {
+    example_prologue!("windowed_average_invalid_window");
+
+    assert!(windowed_average(&[1.0, 2.0, 3.0], 0).is_empty());
+    assert!(windowed_average(&[1.0, 2.0, 3.0], 4).is_empty());
+    assert!(windowed_average(&[], 1).is_empty());
+}
+
+// Same sliding windows as windowed_average but computing the median of each, which unlike the average isn't thrown off
+// by a single outlier. The window sub-slices are borrowed and can't be reordered in place, so each one is cloned into
+// an owned Vec (to_vec) that gets sorted. f64 isn't Ord (because of NaN), total_cmp provides the total order sort_by needs.
+// An even sized window has two middle values, their average is the median.
+pub fn moving_median(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+    data.windows(window)
+        .map(|w| {
+            let mut sorted = w.to_vec();
+            sorted.sort_by(f64::total_cmp);
+            let mid = window / 2;
+            if window.is_multiple_of(2) {
+                (sorted[mid - 1] + sorted[mid]) / 2.0
+            } else {
+                sorted[mid]
+            }
+        })
+        .collect()
+}
+
+#[test]
+pub fn moving_median_odd_window() {
+    example_prologue!("moving_median_odd_window");
+
+    let data = [1.0, 9.0, 2.0, 100.0, 3.0, 4.0];
+    let medians = moving_median(&data, 3);
+    println!("Moving medians : {:?}", medians);
+
+    // The 100.0 outlier doesn't drag the medians up like it would the averages.
+    assert_eq!(medians, vec![2.0, 9.0, 3.0, 4.0]);
+    assert_eq!(data, [1.0, 9.0, 2.0, 100.0, 3.0, 4.0]); // the input is left untouched.
+}
+
+#[test]
+pub fn moving_median_even_window() {
+    example_prologue!("moving_median_even_window");
+
+    assert_eq!(moving_median(&[4.0, 1.0, 3.0, 2.0], 2), vec![2.5, 2.0, 2.5]);
+    assert_eq!(moving_median(&[4.0, 1.0, 3.0, 2.0], 4), vec![2.5]);
+
+    // A window of 0 is rejected, as is a window longer than the data.
+    assert!(moving_median(&[1.0, 2.0], 0).is_empty());
+    assert!(moving_median(&[1.0, 2.0], 3).is_empty());
+}
+
+// Records its name into the shared log when dropped, which lets us observe the order values get dropped in.
+pub struct LoudDrop {
+    name: &'static str,
+    log: std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>,
+}
+
+impl Drop for LoudDrop {
+    fn drop(&mut self) {
+        println!("Dropping {}", self.name);
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+#[test]
+pub fn drop_order() {
+    example_prologue!("drop_order");
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let log = Rc::new(RefCell::new(vec![]));
+    let loud = |name| LoudDrop {
+        name,
+        log: Rc::clone(&log),
+    };
+
+    // A struct drops its fields in their declaration order (unlike locals), after its own Drop (if any) has run.
+    struct Pair {
+        _first: LoudDrop,
+        _second: LoudDrop,
+    }
+
+    {
+        // Locals are dropped in the reverse order of their declaration (LIFO) when the scope ends, since
+        // a later local may hold references to (or otherwise depend on) the earlier ones.
+        let _a = loud("a");
+        let _b = loud("b");
+        let _pair = Pair {
+            _first: loud("pair.first"),
+            _second: loud("pair.second"),
+        };
+        let _c = loud("c");
+    }
+
+    assert_eq!(
+        *log.borrow(),
+        vec!["c", "pair.first", "pair.second", "b", "a"]
+    );
+}
+
 #[test]
 pub fn dangling_reference() {
     example_prologue!("dangling_reference");
@@ -177,4 +316,5 @@ pub fn dangling_reference() {
     //     let s = String::from("hello");
     //     &s
     // }
+    //(enforced by tests/compile_fail/dangling_reference.rs)
 }
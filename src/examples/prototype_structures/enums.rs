@@ -23,32 +23,37 @@ pub fn enums_basic() {
 
 }
 
+//Enums, besides the simple type, can have different variants that can associate data to them.
+//It is declared at the module level so it can be used by other examples as well.
+#[derive(Debug)]
+pub enum Message {
+    Quit, //simple type, no data associated.
+    Write(String), // enum with associated unnamed data (Tuple variant)
+    ChangeColor(i32, i32, i32),// enum with associated unnamed data (Tuple variant)
+    Move { x: i32, y: i32 }, // enum with associated named data (Struct variant)
+
+}
+
+impl Message {
+    //Returns the output line of the message rather than printing it, so that the caller decides what to do with it.
+    pub fn call(&self) -> String {
+        match self{
+            Message::Quit => String::from("Msg : Quit"),
+            Message::Write(str) => format!("Msg : Write {}", str),
+            Message::Move{x, y} => format!("Msg : Move : ({},{})",x , y),
+            Message::ChangeColor(r, g, b) => format!("Msg : ChangeColor : {},{},{}", r, g, b),
+        }
+       
+    }
+}
+
 #[test]
 pub fn enums_advanced() {
     
     example_prologue!("enums_advanced");
 
     //Enums, besides the simple type, can have different variants that can associate data to them.
-    enum Message {
-        Quit, //simple type, no data associated.
-        Write(String), // enum with associated unnamed data (Tuple variant)
-        ChangeColor(i32, i32, i32),// enum with associated unnamed data (Tuple variant)
-        Move { x: i32, y: i32 }, // enum with associated named data (Struct variant)
- 
-    }
-
-    impl Message {
-        fn call(&self) {
-            match self{
-                Message::Quit => println!("Msg : Quit"),
-                Message::Write(str) => println!("Msg : Write {}", str),
-                Message::Move{x, y} => println!("Msg : Move : ({},{})",x , y),
-                Message::ChangeColor(r, g, b) => println!("Msg : ChangeColor : {},{},{}", r, g, b),
-                _ => {},
-            }
-           
-        }
-    }
+    //Check out the module level Message enum declared above this example.
 
     let msg_queue = [
         Message::Write(String::from("Hello World")),
@@ -59,7 +64,146 @@ pub fn enums_advanced() {
 
     //Iterate the msg queue and call each's call function.
     for msg in msg_queue {
-        msg.call();
+        println!("{}", msg.call());
     }
 }
- 
\ No newline at end of file
+ 
+// Since every Message variant represents an action along with its data, the enum lends itself nicely
+// to the command pattern: commands are executed and recorded so that they can later be undone.
+#[derive(Debug, Default)]
+pub struct CommandHistory {
+    pub history: Vec<Message>,
+}
+
+impl CommandHistory {
+    // Runs the command and records it, the history takes ownership of the message.
+    pub fn execute(&mut self, msg: Message) {
+        println!("{}", msg.call());
+        self.history.push(msg);
+    }
+
+    // Re-runs every recorded command in order and collects their output lines, since the history is the log
+    // of every event that took place (event sourcing), replaying it reproduces the original transcript.
+    pub fn replay(&self) -> Vec<String> {
+        self.history.iter().map(Message::call).collect()
+    }
+
+    // Removes the last executed command and gives it back, None if there's nothing to undo.
+    pub fn undo(&mut self) -> Option<Message> {
+        let msg = self.history.pop();
+        if let Some(msg) = &msg {
+            println!("Undo : {:?}", msg);
+        }
+        msg
+    }
+}
+
+#[test]
+pub fn enums_command_pattern() {
+    example_prologue!("enums_command_pattern");
+
+    let mut commands = CommandHistory::default();
+
+    commands.execute(Message::Write(String::from("Hello World")));
+    commands.execute(Message::Move { x: 100, y: 200 });
+    commands.execute(Message::ChangeColor(255, 0, 0));
+
+    assert_eq!(commands.history.len(), 3);
+
+    // Undo hands back the last executed command.
+    assert!(matches!(commands.undo(), Some(Message::ChangeColor(255, 0, 0))));
+    assert_eq!(commands.history.len(), 2);
+    assert!(matches!(
+        commands.history.last(),
+        Some(Message::Move { x: 100, y: 200 })
+    ));
+}
+
+#[test]
+pub fn enums_command_pattern_replay() {
+    example_prologue!("enums_command_pattern_replay");
+
+    let mut commands = CommandHistory::default();
+    let messages = [
+        Message::Write(String::from("Hello World")),
+        Message::Move { x: 100, y: 200 },
+        Message::Quit,
+    ];
+
+    // Keep the transcript of the original execution to compare the replay against.
+    let mut transcript = vec![];
+    for msg in messages {
+        transcript.push(msg.call());
+        commands.execute(msg);
+    }
+
+    let replayed = commands.replay();
+    println!("Replayed transcript : {:?}", replayed);
+
+    assert_eq!(replayed, transcript);
+    assert_eq!(
+        replayed,
+        vec!["Msg : Write Hello World", "Msg : Move : (100,200)", "Msg : Quit"]
+    );
+}
+
+#[test]
+pub fn enums_command_pattern_undo_empty() {
+    example_prologue!("enums_command_pattern_undo_empty");
+
+    let mut commands = CommandHistory::default();
+
+    commands.execute(Message::Quit);
+
+    assert!(matches!(commands.undo(), Some(Message::Quit)));
+    assert!(commands.undo().is_none()); // nothing left to undo.
+    assert!(commands.history.is_empty());
+}
+
+// Simple (fieldless) enum variants can be given explicit integer discriminants, and cast into them via 'as'.
+// The #[repr(i32)] attribute sets the underlying integer type used to store the discriminant.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Priority {
+    Low = 1,
+    Medium = 5,
+    High = 10,
+    Critical = -1, // discriminants don't need to be ordered or positive.
+}
+
+impl Priority {
+    pub const ALL: [Priority; 4] = [
+        Priority::Low,
+        Priority::Medium,
+        Priority::High,
+        Priority::Critical,
+    ];
+
+    // The reverse direction can't be done via 'as' since not every i32 maps to a variant,
+    // so we match the value against every discriminant and return None if none matches.
+    pub fn from_i32(value: i32) -> Option<Priority> {
+        Priority::ALL
+            .into_iter()
+            .find(|&priority| priority as i32 == value)
+    }
+}
+
+#[test]
+pub fn enums_discriminant_cast() {
+    example_prologue!("enums_discriminant_cast");
+
+    for priority in Priority::ALL {
+        let value = priority as i32;
+        println!("{:?} as i32 = {}", priority, value);
+
+        // Round trip: variant -> i32 -> variant.
+        assert_eq!(Priority::from_i32(value), Some(priority));
+    }
+
+    assert_eq!(Priority::High as i32, 10);
+    assert_eq!(Priority::from_i32(-1), Some(Priority::Critical));
+
+    // Integers that don't match any discriminant are rejected rather than producing an invalid enum.
+    assert_eq!(Priority::from_i32(0), None);
+    assert_eq!(Priority::from_i32(42), None);
+}
@@ -9,35 +9,36 @@
  
 use crate::*; //Import the entire crate.
 
-pub fn struct_def_and_init() {
-
-    example_prologue!("struct_def_and_init");
+//Defining a Rectangle struct, declared at the module level so it can be used by other examples as well.
+#[derive(Debug)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
 
-    //Defining a Rectangle struct.
-    struct Rectangle {
-        width: u32,
-        height: u32,
+//impl of methods for Rectangle struct.
+impl Rectangle {
+    //reference to self as a function param points to the current instance of which this method is called upon.
+    pub fn area(&self) -> u32 {
+        self.width * self.height
     }
+}
 
-    //impl of methods for Rectangle struct.
-    impl Rectangle {
-        //reference to self as a function param points to the current instance of which this method is called upon.
-        fn area(&self) -> u32 {
-            self.width * self.height
+// A struct can define multiple impl blocks, but prefer to use one if in same file/location.
+impl Rectangle {
+    // This is a static function that is listed under this struct, it doesn't require an instance of this struct
+    // which explains why it lacks the &self param, it is invoked via Rectangle::default().
+    fn default() -> Rectangle {
+        Rectangle {
+            width: 800,
+            height: 600,
         }
     }
+}
 
-    // A struct can define multiple impl blocks, but prefer to use one if in same file/location.
-    impl Rectangle {
-        // This is a static function that is listed under this struct, it doesn't require an instance of this struct
-        // which explains why it lacks the &self param, it is invoked via Rectangle::default().
-        fn default() -> Rectangle {
-            Rectangle {
-                width: 800,
-                height: 600,
-            }
-        }
-    }
+pub fn struct_def_and_init() {
+
+    example_prologue!("struct_def_and_init");
 
     //Instantiating Structs
     let rect1 = Rectangle {
@@ -95,3 +96,48 @@ pub fn structs_flavors() {
     println!("unit_like: {:?} => UnitLike struct", unit_like);
 
 }
+
+//Structs with many fields can be tedious to instantiate, a builder allows setting the fields one by one via chained calls.
+//Writing a builder by hand for every struct is a lot of boilerplate, so the User struct and its UserBuilder are
+//both generated by the builder macro (check out macros.rs).
+builder!(User => UserBuilder {
+    username: String,
+    email: String,
+    sign_in_count: u64,
+    active: bool,
+});
+
+#[test]
+pub fn struct_builder() {
+    example_prologue!("struct_builder");
+
+    let user = User::builder()
+        .username("rusty".to_owned())
+        .email("rusty@example.com".to_owned())
+        .sign_in_count(1)
+        .active(true)
+        .build()
+        .unwrap();
+
+    println!("Built user : {:?}", user);
+
+    assert_eq!(user.username, "rusty");
+    assert_eq!(user.email, "rusty@example.com");
+    assert_eq!(user.sign_in_count, 1);
+    assert!(user.active);
+}
+
+#[test]
+pub fn struct_builder_missing_field() {
+    example_prologue!("struct_builder_missing_field");
+
+    //Omitting a field still compiles, the missing field is reported by 'build' instead.
+    let result = User::builder()
+        .username("rusty".to_owned())
+        .sign_in_count(1)
+        .active(true)
+        .build();
+
+    println!("Build result : {:?}", result);
+    assert_eq!(result, Err("missing field 'email'".to_owned()));
+}
@@ -10,6 +10,22 @@
 
 use crate::*; //Import the entire crate.
 
+// Unions in Rust can have non-zero offsets and some other different guarantees than
+// a conventional C Enum (from the C lang), if we care to have it conform to a C enum
+// for interopability and what not, we can mark it with the #[repr(C)] attribute.
+// which guarantees it to have the same size and alignment as an equivalent C union declaration
+// in the C language  for the target platform. The union will have a size of the maximum size of
+// all of its fields rounded to its alignment, and an alignment of the maximum alignment of all
+// of its fields. These maximums may come from different fields.
+// It is declared at the module level so it can be used by other examples as well.
+
+#[repr(C)] // C union conformation attribute.
+#[derive(Clone, Copy)]
+pub union CharOrInt {
+    a: char, //Size = 1 byte
+    b: i32,  //Size = 4 bytes
+} // Total size = size of largest field (b) = 4 bytes.
+
 #[test]
 pub fn main() {
     
@@ -17,19 +33,7 @@ pub fn main() {
 
     use std::mem;
 
-    // Unions in Rust can have non-zero offsets and some other different guarantees than
-    // a conventional C Enum (from the C lang), if we care to have it conform to a C enum
-    // for interopability and what not, we can mark it with the #[repr(C)] attribute.
-    // which guarantees it to have the same size and alignment as an equivalent C union declaration
-    // in the C language  for the target platform. The union will have a size of the maximum size of
-    // all of its fields rounded to its alignment, and an alignment of the maximum alignment of all
-    // of its fields. These maximums may come from different fields.
-
-    #[repr(C)] // C union conformation attribute.
-    union CharOrInt {
-        a: char, //Size = 1 byte
-        b: i32,  //Size = 4 bytes
-    } // Total size = size of largest field (b) = 4 bytes.
+    // Check out the module level CharOrInt union declared above this example.
 
     //Declare an instance
     let mut u = CharOrInt { b: 32 };
@@ -51,3 +55,167 @@ pub fn main() {
         )
     }
 }
+
+// Union fields must either implement the Copy trait or be wrapped by a ManuallyDrop, since the compiler can't
+// know which field is currently in use, it can't know which destructor to run when the union gets dropped.
+// ManuallyDrop prevents the compiler from ever calling the destructor of the wrapped value, leaving it up to us
+// to drop it explicitly (via ManuallyDrop::drop or ptr::drop_in_place), otherwise its resources are leaked.
+
+use std::mem::ManuallyDrop;
+
+pub union StringOrInt {
+    pub text: ManuallyDrop<String>, // String owns heap memory, so it isn't Copy.
+    pub number: i64,
+}
+
+#[test]
+pub fn union_manually_drop() {
+    example_prologue!("union_manually_drop");
+
+    // Initializing a union is safe, only reading from it is unsafe.
+    let mut u = StringOrInt {
+        text: ManuallyDrop::new(String::from("Hello Union")),
+    };
+
+    unsafe {
+        // Safe to read since we know that 'text' is the field in use.
+        println!("u.text = {}", *u.text);
+        assert_eq!(*u.text, "Hello Union");
+
+        // Free the String's heap memory before the union goes out of scope, since the union won't do it.
+        // **Reading u.text after this line would be a use after free (undefined behavior).
+        ManuallyDrop::drop(&mut u.text);
+    }
+
+    // Now that the String is dropped, the storage can be reused by another field.
+    u.number = 42;
+    unsafe {
+        println!("u.number = {}", u.number);
+    }
+}
+
+#[test]
+pub fn union_manually_drop_no_leak() {
+    example_prologue!("union_manually_drop_no_leak");
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // A wrapper counting how many times it got dropped.
+    struct DropCounter {
+        text: String,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    union CounterOrInt {
+        counter: ManuallyDrop<DropCounter>,
+        #[allow(dead_code)]
+        number: i64,
+    }
+
+    let drops = Rc::new(Cell::new(0));
+
+    {
+        let mut u = CounterOrInt {
+            counter: ManuallyDrop::new(DropCounter {
+                text: String::from("Tracked"),
+                drops: Rc::clone(&drops),
+            }),
+        };
+
+        unsafe {
+            assert_eq!(u.counter.text, "Tracked");
+
+            // drop_in_place runs the destructor of the pointed to value without moving it.
+            std::ptr::drop_in_place(&mut *u.counter as *mut DropCounter);
+        }
+        assert_eq!(drops.get(), 1);
+    } // 'u' goes out of scope here, no destructor runs for its fields.
+
+    assert_eq!(drops.get(), 1); // dropped exactly once, no leak and no double drop.
+    assert_eq!(Rc::strong_count(&drops), 1); // the clone held by the counter was released.
+}
+
+// Every access to the raw CharOrInt union is unsafe because nothing keeps track of which field was last written.
+// Reading an i32 as a char is undefined behavior if the integer isn't a valid unicode scalar value.
+// We can build a safe API over it by pairing the union with a tag recording the field in use, and only
+// reading the field that matches the tag. (This is essentially what a Rust enum does for us under the hood)
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CharOrIntTag {
+    Char,
+    Int,
+}
+
+#[derive(Clone, Copy)]
+pub struct TaggedCharOrInt {
+    tag: CharOrIntTag,
+    value: CharOrInt, // private, so the tag can't get out of sync with the field in use.
+}
+
+impl TaggedCharOrInt {
+    pub fn new_char(c: char) -> Self {
+        TaggedCharOrInt {
+            tag: CharOrIntTag::Char,
+            value: CharOrInt { a: c },
+        }
+    }
+
+    pub fn new_int(i: i32) -> Self {
+        TaggedCharOrInt {
+            tag: CharOrIntTag::Int,
+            value: CharOrInt { b: i },
+        }
+    }
+
+    pub fn set_char(&mut self, c: char) {
+        self.value.a = c; // writing a Copy field is safe.
+        self.tag = CharOrIntTag::Char;
+    }
+
+    pub fn set_int(&mut self, i: i32) {
+        self.value.b = i;
+        self.tag = CharOrIntTag::Int;
+    }
+
+    pub fn as_char(&self) -> Option<char> {
+        match self.tag {
+            // Safe since the tag guarantees that 'a' holds a valid char.
+            CharOrIntTag::Char => Some(unsafe { self.value.a }),
+            CharOrIntTag::Int => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i32> {
+        match self.tag {
+            CharOrIntTag::Int => Some(unsafe { self.value.b }),
+            CharOrIntTag::Char => None,
+        }
+    }
+}
+
+#[test]
+pub fn tagged_union() {
+    example_prologue!("tagged_union");
+
+    let mut value = TaggedCharOrInt::new_int(-1); // -1 isn't a valid char.
+
+    assert_eq!(value.as_int(), Some(-1));
+    assert_eq!(value.as_char(), None); // reading the wrong field is rejected rather than being UB.
+
+    value.set_char('R');
+    println!("as_char = {:?}, as_int = {:?}", value.as_char(), value.as_int());
+
+    assert_eq!(value.as_char(), Some('R'));
+    assert_eq!(value.as_int(), None);
+
+    value.set_int(32);
+    assert_eq!(value.as_int(), Some(32));
+    assert_eq!(TaggedCharOrInt::new_char('x').as_int(), None);
+}
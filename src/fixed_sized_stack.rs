@@ -0,0 +1,293 @@
+// A fixed capacity stack backed by a raw heap allocation, extracted out of the unsafe_ops examples
+// (src/examples/advanced/unsafe_ops.rs) into the library so it can be reused and benchmarked (benches/stack_bench.rs).
+// Check out the unsafe_ops examples for a walkthrough of how it is used.
+
+use libc::c_void; //https://crates.io/crates/libc
+
+use std::alloc::Layout;
+
+// The allocation strategy of the FixedSizedStack is abstracted behind a trait, so that the stack doesn't
+// depend on a concrete allocator and a different one can be plugged in (dependency injection).
+// The methods are unsafe since they deal with raw memory, the caller has to pass the same layout to
+// 'dealloc' that was used to 'alloc' the pointer.
+#[allow(clippy::missing_safety_doc)] // the safety contract is described above.
+pub trait RawAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8;
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout);
+}
+
+// Allocates via the native C allocator (malloc/free) through the libc FFI bindings.
+// malloc only guarantees an alignment suitable for the fundamental C types (MALLOC_ALIGN below), reading a T from a
+// misaligned address is undefined behavior, so over-aligned types (e.g. #[repr(align(64))]) go through posix_memalign
+// instead, which takes the alignment to honor. Both are released by free.
+#[derive(Default)]
+pub struct LibcAllocator;
+
+// The alignment guaranteed by malloc, that of the largest fundamental type (16 bytes on 64 bit platforms).
+const MALLOC_ALIGN: usize = 2 * std::mem::size_of::<usize>();
+
+impl RawAllocator for LibcAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.align() <= MALLOC_ALIGN {
+            return libc::malloc(layout.size()) as *mut u8;
+        }
+
+        let mut pointer = std::ptr::null_mut();
+        // posix_memalign reports failures via its return value rather than errno, the pointer is left untouched (null).
+        libc::posix_memalign(&mut pointer, layout.align(), layout.size());
+        pointer as *mut u8
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, _: Layout) {
+        libc::free(pointer as *mut c_void);
+    }
+}
+
+// Allocates via the Rust global allocator (std::alloc), which honors the alignment of the layout.
+#[derive(Default)]
+pub struct StdAllocator;
+
+impl RawAllocator for StdAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() == 0 {
+            // Allocating 0 bytes via std::alloc::alloc is undefined behavior, a dangling but well aligned
+            // pointer is enough since there is nothing to read or write.
+            return layout.align() as *mut u8;
+        }
+        std::alloc::alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        if layout.size() != 0 {
+            std::alloc::dealloc(pointer, layout);
+        }
+    }
+}
+
+// The stack is generic over its allocator, defaulting to the libc one when not specified (e.g. FixedSizedStack<u32, 10>).
+pub struct FixedSizedStack<T, const N: usize, A: RawAllocator = LibcAllocator> {
+    // N is a constant generic parameter, you pass in a constant size.
+    pointer: *mut T, // this is the raw mutable pointer to the memory allocated on the heap.
+    curr_size: usize, //
+    allocator: A,
+    // Set once the memory is freed, 'free' may be called explicitly and then again by Drop, the flag makes sure
+    // the allocation is only handed back to the allocator once (freeing it twice is undefined behavior).
+    freed: bool,
+}
+
+// Implement the Drop trait to free the memory on lifetime expiration.
+impl<T, const N: usize, A: RawAllocator> Drop for FixedSizedStack<T, N, A> {
+    fn drop(&mut self) {
+        println!("Freed the FixedSizedStack memory!");
+        self.free();
+    }
+}
+//implement the Deref trait for our struct so that we can dereference it by the '*' operator.
+impl<T, const N: usize, A: RawAllocator> std::ops::Deref for FixedSizedStack<T, N, A> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // There's no top to dereference on an empty (or freed) stack, reading below the allocation would be undefined
+        // behavior, so panic the same way indexing an empty Vec does.
+        assert!(self.curr_size > 0, "Can't dereference an empty FixedSizedStack!");
+        unsafe {
+            //unsafe block required when dealing with raw pointers.
+            let offset = self.curr_size - 1;
+            self.pointer.add(offset).as_ref().unwrap() //As expected, it will panic if the reference is invalid.
+        }
+    }
+}
+
+impl<T, const N: usize, A: RawAllocator + Default> FixedSizedStack<T, N, A> {
+    pub fn new() -> FixedSizedStack<T, N, A> {
+        //factory method to create a new FixedSizedStack instance via a defaulted allocator.
+        Self::new_in(A::default())
+    }
+}
+
+impl<T, const N: usize, A: RawAllocator> FixedSizedStack<T, N, A> {
+    // The memory layout (size & alignment) of the whole fixed stack allocation.
+    fn layout() -> Layout {
+        Layout::array::<T>(N).unwrap()
+    }
+
+    // Zero sized types (e.g. '()' or unit-like structs) and N == 0 both make for a 0 bytes allocation,
+    // which allocators handle inconsistently (malloc(0) may return null), so these skip the allocator entirely.
+    fn is_zero_sized() -> bool {
+        Self::layout().size() == 0
+    }
+
+    pub fn new_in(allocator: A) -> FixedSizedStack<T, N, A> {
+        //factory method to create a new FixedSizedStack instance backed by the given allocator.
+        if Self::is_zero_sized() {
+            // A dangling (non null and well aligned) pointer is valid for zero sized reads and writes,
+            // so for ZSTs we only need to keep track of the curr_size.
+            return FixedSizedStack {
+                pointer: std::ptr::NonNull::dangling().as_ptr(),
+                curr_size: 0,
+                allocator,
+                freed: false,
+            };
+        }
+
+        // allocate memory on the heap that fits the fixed stack size, unsafe block required when dealing with raw pointers.
+        let pointer = unsafe { allocator.alloc(Self::layout()) as *mut T };
+        if pointer.is_null() {
+            // Out of memory, pushing would write through the null pointer. handle_alloc_error is the std way of
+            // reporting it, it aborts the process (the same happens when a Vec or Box fails to allocate).
+            std::alloc::handle_alloc_error(Self::layout());
+        }
+
+        FixedSizedStack {
+            pointer,
+            curr_size: 0,
+            allocator,
+            freed: false,
+        }
+    }
+
+    // Returns whether the memory got freed by this call, false if it was already freed.
+    pub fn free(&mut self) -> bool {
+        if self.freed {
+            return false; // Guarantee no double freeing problems.
+        }
+        unsafe {
+            // The stack owns its live elements, so they are dropped (e.g. a String frees its buffer) before the memory
+            // holding them is. Popped slots aren't live anymore and are left alone (check out 'pop').
+            // curr_size is reset first so that a panicking element destructor can't get the elements dropped twice.
+            let live = std::mem::take(&mut self.curr_size);
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(self.pointer, live));
+            if !Self::is_zero_sized() {
+                self.allocator.dealloc(self.pointer as *mut u8, Self::layout()); //free the memory allocated on the heap.
+            }
+        }
+        self.pointer = std::ptr::null_mut(); //set the pointer to null.
+        self.curr_size = 0;
+        self.freed = true;
+        true
+    }
+
+    pub fn push(&mut self, value: T) -> bool {
+        //push element T on the stack, the value is moved in so the stack owns it from now on.
+        //returns whether the element was pushed, a rejected value is dropped right away.
+
+        if self.curr_size >= N {
+            //bound checking, a stack of N == 0 is always full.
+            println!("Failed to push, Stack is full!");
+            return false;
+        }
+
+        if self.freed {
+            //the pointer is null past this point, writing through it would be undefined behavior.
+            println!("Failed to push, Stack was freed!");
+            return false;
+        }
+
+        unsafe {
+            //move the value into its slot on the heap, ptr::write doesn't read nor drop the old (uninitialized or
+            //popped) content of the slot. The pointer is dangling for ZSTs, which is fine for a zero sized write.
+            std::ptr::write(self.pointer.add(self.curr_size), value);
+
+            self.curr_size += 1; // increment size after pushing the element.
+        }
+        true
+    }
+
+    pub fn pop(&mut self) -> *const T {
+        // pop element T from the stack and return it as a raw pointer to its slot.
+        // The popped value isn't live anymore, the stack won't drop it and the next push overwrites it, so the pointer is
+        // only valid until then. Its ownership passes to the caller who may take it via ptr::read, otherwise it's leaked.
+
+        if self.curr_size == 0 {
+            //Bound checking
+            println!("Failed to pop, Stack is empty!");
+            return std::ptr::null(); //return nullpointer if the stack is empty.
+        }
+        unsafe {
+            let offset = self.curr_size - 1;
+            let res = self.pointer.add(offset); // get top of the stack
+            self.curr_size -= 1; // decrement size after popping the element.
+            res // return the popped element.
+        }
+    }
+
+    pub fn empty(&self) -> bool {
+        self.curr_size == 0
+    }
+
+    // Moves the live elements out into a Vec, from the bottom to the top of the stack.
+    // Each element is read out of its slot (a bitwise move), then curr_size is reset so that dropping the stack only
+    // frees its memory and doesn't drop the moved out elements a second time (a double free for e.g. Strings).
+    pub fn into_vec(mut self) -> Vec<T> {
+        let live = std::mem::take(&mut self.curr_size);
+        (0..live).map(|i| unsafe { std::ptr::read(self.pointer.add(i)) }).collect()
+    } // self drops here, freeing the memory.
+
+    // Pushes clones of the items in order until a push fails (the stack is full or was freed), returns how many of
+    // them were pushed.
+    pub fn extend_from_slice(&mut self, items: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        let mut pushed = 0;
+        for item in items {
+            if !self.push(item.clone()) {
+                break; // full, or freed.
+            }
+            pushed += 1;
+        }
+        pushed
+    }
+
+    // Borrowing iterator over the live elements from the bottom to the top of the stack, unlike popping
+    // it doesn't modify the stack so it can be used to inspect its content as many times as needed.
+    // The references point to values owned by the stack ('push' moves them in), never to copies of values owned by
+    // someone else, so they stay valid for as long as the stack is borrowed, even for heap owning types like String.
+    pub fn iter(&self) -> StackIter<'_, T, N, A> {
+        StackIter {
+            stack: self,
+            index: 0,
+        }
+    }
+}
+
+impl<T, const N: usize, A: RawAllocator + Default> Default for FixedSizedStack<T, N, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Two stacks are equal when they hold the same number of elements and their live elements are equal in order,
+// whatever is left in the unused (uninitialized) slots is never read. The allocators are irrelevant to the content,
+// so stacks backed by different allocators can be compared as well. The comparison goes through 'iter', so it only
+// ever reads values owned by the stacks, whatever T is.
+impl<T: PartialEq, const N: usize, A: RawAllocator, B: RawAllocator> PartialEq<FixedSizedStack<T, N, B>>
+    for FixedSizedStack<T, N, A>
+{
+    fn eq(&self, other: &FixedSizedStack<T, N, B>) -> bool {
+        self.curr_size == other.curr_size && self.iter().eq(other.iter())
+    }
+}
+
+// The iterator holds a shared reference to the stack, so the borrow checker won't let the stack be
+// mutated (pushed to, popped from or freed) while an iteration is in progress.
+pub struct StackIter<'a, T, const N: usize, A: RawAllocator = LibcAllocator> {
+    stack: &'a FixedSizedStack<T, N, A>,
+    index: usize, // index of the next element to yield.
+}
+
+impl<'a, T, const N: usize, A: RawAllocator> Iterator for StackIter<'a, T, N, A> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Only the slots below curr_size hold pushed values, the rest of the allocation is uninitialized.
+        if self.index >= self.stack.curr_size {
+            return None;
+        }
+        unsafe {
+            let item = self.stack.pointer.add(self.index).as_ref(); // None if the pointer is null (freed).
+            self.index += 1;
+            item
+        }
+    }
+}
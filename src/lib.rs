@@ -0,0 +1,17 @@
+//! Reusable utilities extracted out of the rust-playground examples.
+//!
+//! The examples themselves live in the binary crate behind `#[cfg(test)]` (see main.rs), this library only
+//! exposes the modules that are useful on their own so other crates can depend on them.
+//!
+//! ```
+//! use rust_playground::util;
+//!
+//! util::print_type_of("Type of x =", &1u8); // Type of x = u8
+//!
+//! let lines = util::wrap_text("the quick brown fox", 10);
+//! assert_eq!(lines, vec!["the quick", "brown fox"]);
+//! ```
+
+pub mod fixed_sized_stack;
+pub mod stack;
+pub mod util;
@@ -1,8 +1,18 @@
 
 //Modules tree.
-pub mod util;
+
+//The reusable modules live in the library crate (lib.rs) so they can be used by other crates, re-exporting
+//them here keeps them accessible to the examples through crate::util, crate::fixed_sized_stack
+//and crate::stack.
+pub use rust_playground::{fixed_sized_stack, stack, util};
+
 #[cfg(test)]
 pub mod examples {
+    //capture comes first and is marked with #[macro_use] so that its print!/println! macros are textually in scope,
+    //shadowing the std ones, in every module declared after it (check out capture.rs).
+    #[macro_use]
+    pub mod capture;
+
     pub mod common_concepts;
     pub mod control_flow;
 
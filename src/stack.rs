@@ -0,0 +1,39 @@
+// A safe LIFO stack backed by a Vec, the counterpart of the raw memory based FixedSizedStack (check out
+// fixed_sized_stack.rs), moved out of the collections examples into the library so it can be benchmarked against it
+// (benches/stack_bench.rs). Check out the collections examples for how it is used. The Vec handles the allocation, growth and freeing of the memory, so no unsafe code is needed.
+#[derive(Debug, Default)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn push(&mut self, value: T) {
+        self.items.push(value);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+// Implementing FromIterator allows collecting any iterator into a Stack, e.g. (1..=5).collect::<Stack<_>>().
+// The items are pushed in the order they are yielded, so the last collected item ends up at the top of the stack.
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
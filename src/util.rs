@@ -1,3 +1,233 @@
 pub fn print_type_of<T>(str: &str, _: &T) {
     println!("{} {}", str, std::any::type_name::<T>())
 }
+
+// Breaks a paragraph into lines no longer than 'width' characters, splitting on whitespace so words
+// are kept whole, unless a single word is longer than 'width' in which case it gets split across lines.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+        let line_len = line.chars().count();
+
+        if line_len > 0 && line_len + 1 + word_len <= width {
+            // The word fits on the current line along with its separating space.
+            line.push(' ');
+            line.push_str(word);
+            continue;
+        }
+
+        // The word doesn't fit, start a new line.
+        if line_len > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        if word_len <= width {
+            line.push_str(word);
+        } else {
+            // The word is too long for any line, split it into 'width' sized pieces.
+            let chars = word.chars().collect::<Vec<_>>();
+            let mut pieces = chars.chunks(width.max(1)).map(|c| c.iter().collect::<String>());
+            line = pieces.next_back().unwrap_or_default(); // the last piece may be continued by the next word.
+            lines.extend(pieces);
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+// Shuffles the items in place using the Fisher-Yates algorithm: walking from the last item down, every item is swapped
+// with a randomly picked one among itself and the items before it, giving every permutation an equal chance.
+// The randomness comes entirely from 'rng', so passing a generator seeded with a fixed value gives deterministic results.
+pub fn shuffle<T>(items: &mut [T], rng: &mut impl rand::Rng) {
+    for i in (1..items.len()).rev() {
+        let j = rng.gen_range(0..=i);
+        items.swap(i, j);
+    }
+}
+
+// Restricts the value to the [min, max] range. Only requires PartialOrd so it works for floats as well as integers
+// (std's Ord::clamp requires a total order, which floats don't have because of NaN).
+pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+// Linearly remaps the value from the input range to the output range, e.g. 5 in (0, 10) maps to 50 in (0, 100).
+// Values outside of the input range are extrapolated, clamp the result if that's not desired.
+pub fn map_range(value: f64, in_range: (f64, f64), out_range: (f64, f64)) -> f64 {
+    let (in_min, in_max) = in_range;
+    let (out_min, out_max) = out_range;
+    out_min + (value - in_min) * (out_max - out_min) / (in_max - in_min)
+}
+
+// Renders the map as a two column table sorted by key, one "key | value" row per line. The keys are padded to the
+// longest one so the separators line up. Widths are measured after formatting, in chars rather than bytes.
+// K has to be Ord as well to be sortable, HashMap iteration order being arbitrary.
+pub fn format_table<K: std::fmt::Display + Ord, V: std::fmt::Display>(
+    map: &std::collections::HashMap<K, V>,
+) -> String {
+    let mut rows = map.iter().collect::<Vec<_>>();
+    rows.sort_by_key(|(key, _)| *key);
+
+    let rows = rows
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect::<Vec<_>>();
+    let width = rows.iter().map(|(key, _)| key.chars().count()).max().unwrap_or(0);
+
+    rows.into_iter()
+        .map(|(key, value)| format!("{:<width$} | {}\n", key, value, width = width))
+        .collect()
+}
+
+// Merges the overlapping intervals into the minimal set of disjoint ones, e.g. (1, 4), (2, 6), (8, 9) gives (1, 6), (8, 9).
+// The intervals are inclusive, so touching ones such as (1, 3) and (3, 5) share a point and get merged as well.
+// Once sorted by start, an interval can only overlap the last merged one, which makes it a single pass after the sort.
+// The input may be unsorted and is left untouched, the intervals are expected to be (start, end) with start <= end.
+pub fn merge_intervals(intervals: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut sorted = intervals.to_vec();
+    sorted.sort_unstable();
+
+    let mut merged: Vec<(i32, i32)> = Vec::with_capacity(sorted.len());
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            // Overlaps (or touches) the last merged interval, extend it if this one ends later.
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+#[test]
+fn wrap_text_normal() {
+    assert_eq!(
+        wrap_text("The quick brown fox jumps over the lazy dog", 10),
+        vec!["The quick", "brown fox", "jumps over", "the lazy", "dog"]
+    );
+    // Extra whitespace and line breaks in the input are collapsed.
+    assert_eq!(wrap_text("  Hello \n  World  ", 5), vec!["Hello", "World"]);
+}
+
+#[test]
+fn wrap_text_long_word() {
+    assert_eq!(
+        wrap_text("a supercalifragilistic word", 6),
+        vec!["a", "superc", "alifra", "gilist", "ic", "word"]
+    );
+    assert_eq!(wrap_text("abcdefg h", 3), vec!["abc", "def", "g h"]);
+}
+
+#[test]
+fn wrap_text_short_input() {
+    assert_eq!(wrap_text("Hello World", 80), vec!["Hello World"]);
+    assert!(wrap_text("", 80).is_empty());
+}
+
+#[test]
+fn shuffle_seeded() {
+    use rand::SeedableRng;
+
+    let mut items = [1, 2, 3, 4, 5, 6, 7, 8];
+    shuffle(&mut items, &mut rand::rngs::StdRng::seed_from_u64(42));
+    assert_eq!(items, [2, 6, 8, 7, 1, 3, 4, 5]);
+
+    // The same seed always gives the same permutation.
+    let mut again = [1, 2, 3, 4, 5, 6, 7, 8];
+    shuffle(&mut again, &mut rand::rngs::StdRng::seed_from_u64(42));
+    assert_eq!(items, again);
+
+    // The elements are only moved around, none is lost or duplicated.
+    let mut sorted = items;
+    sorted.sort();
+    assert_eq!(sorted, [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn shuffle_empty_and_single() {
+    let mut rng = rand::thread_rng();
+
+    let mut empty: [i32; 0] = [];
+    shuffle(&mut empty, &mut rng);
+
+    let mut single = [1];
+    shuffle(&mut single, &mut rng);
+    assert_eq!(single, [1]);
+}
+
+#[test]
+fn clamp_bounds() {
+    assert_eq!(clamp(-5, 0, 10), 0);
+    assert_eq!(clamp(5, 0, 10), 5);
+    assert_eq!(clamp(15, 0, 10), 10);
+    assert_eq!(clamp(0.5, 0.0, 1.0), 0.5);
+    assert_eq!(clamp(1.5, 0.0, 1.0), 1.0);
+}
+
+#[test]
+fn map_range_midpoint() {
+    assert_eq!(map_range(5.0, (0.0, 10.0), (0.0, 100.0)), 50.0);
+    assert_eq!(map_range(0.0, (-1.0, 1.0), (20.0, 40.0)), 30.0);
+    // Reversed output range.
+    assert_eq!(map_range(2.5, (0.0, 10.0), (1.0, 0.0)), 0.75);
+    // The range bounds map onto each other.
+    assert_eq!(map_range(10.0, (0.0, 10.0), (-1.0, 1.0)), 1.0);
+}
+
+#[test]
+fn format_table_aligned() {
+    let map = std::collections::HashMap::from([("FAMAS", 25000), ("P90", 2350), ("AK47", 3000)]);
+    let table = format_table(&map);
+
+    assert_eq!(table, "AK47  | 3000\nFAMAS | 25000\nP90   | 2350\n");
+    // Every separator sits at the same column, right after the longest key.
+    assert!(table.lines().all(|row| row.find('|') == Some("FAMAS ".len())));
+}
+
+#[test]
+fn format_table_sorted() {
+    // Sorted by the keys themselves, 9 comes before 10 unlike their string forms.
+    let map = std::collections::HashMap::from([(10, 'c'), (9, 'b'), (100, 'd'), (1, 'a')]);
+    assert_eq!(format_table(&map), "1   | a\n9   | b\n10  | c\n100 | d\n");
+
+    assert_eq!(format_table(&std::collections::HashMap::<String, i32>::new()), "");
+}
+
+#[test]
+fn merge_intervals_disjoint() {
+    assert_eq!(merge_intervals(&[(1, 2), (4, 5), (7, 9)]), vec![(1, 2), (4, 5), (7, 9)]);
+    // Unsorted input comes out sorted.
+    assert_eq!(merge_intervals(&[(7, 9), (1, 2), (4, 5)]), vec![(1, 2), (4, 5), (7, 9)]);
+}
+
+#[test]
+fn merge_intervals_overlapping() {
+    assert_eq!(merge_intervals(&[(8, 9), (2, 6), (1, 4)]), vec![(1, 6), (8, 9)]);
+    // An interval contained in another one doesn't shrink it.
+    assert_eq!(merge_intervals(&[(1, 10), (2, 3), (4, 12)]), vec![(1, 12)]);
+}
+
+#[test]
+fn merge_intervals_touching() {
+    assert_eq!(merge_intervals(&[(3, 5), (1, 3)]), vec![(1, 5)]);
+    // Adjacent integers without a shared point aren't touching.
+    assert_eq!(merge_intervals(&[(1, 3), (4, 5)]), vec![(1, 3), (4, 5)]);
+}
+
+#[test]
+fn merge_intervals_empty() {
+    assert!(merge_intervals(&[]).is_empty());
+    assert_eq!(merge_intervals(&[(-2, -2)]), vec![(-2, -2)]);
+}
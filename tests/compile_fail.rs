@@ -0,0 +1,11 @@
+// The ownership examples point out lines that won't compile by leaving them commented out, the snippets under
+// tests/compile_fail mirror those lines and trybuild checks that each of them keeps failing to compile with the
+// error recorded in its .stderr file.
+// After an intended change to a snippet, the .stderr files can be regenerated via
+// TRYBUILD=overwrite cargo test --test compile_fail
+
+#[test]
+fn ownership_compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
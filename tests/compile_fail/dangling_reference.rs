@@ -0,0 +1,11 @@
+// Mirrors the 'dangling_reference' example in src/examples/ownership.rs.
+// s is dropped at the end of dangle, a reference to it would outlive the data it points to.
+
+fn dangle() -> &'static String {
+    let s = String::from("hello");
+    &s
+}
+
+fn main() {
+    println!("{}", dangle());
+}
@@ -0,0 +1,14 @@
+// Mirrors the 'refs_and_burrowing' example in src/examples/ownership.rs.
+// ms1 is still in use when ms2 borrows s mutably, only one mutable reference is allowed at a time.
+
+fn main() {
+    let mut s = String::from("hello");
+
+    let ms1 = &mut s;
+    ms1.push_str(" world!");
+
+    let ms2 = &mut s;
+    ms2.push('!');
+
+    println!("ms1 = {}", ms1);
+}
@@ -0,0 +1,14 @@
+// Mirrors the 'function_ownership' example in src/examples/ownership.rs.
+// Passing s by value moves it into the function, it is dropped at the end of the call.
+
+fn takes_ownership(some_string: String) {
+    println!("{}", some_string);
+}
+
+fn main() {
+    let s = String::from("hello");
+
+    takes_ownership(s);
+
+    println!("s = {}", s);
+}
@@ -0,0 +1,10 @@
+// Mirrors the 'ownership' example in src/examples/ownership.rs.
+// Assignment moves the String out of s1, using s1 afterwards is a use after move.
+
+fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+
+    println!("s2 = {}", s2);
+    println!("s1 = {}", s1);
+}